@@ -1,5 +1,5 @@
 // DeepDream post-processing effect: yellow tint, procedural eyes, swirl tendrils,
-// and chromatic aberration.
+// chromatic aberration, vignette and film grain.
 
 #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
 
@@ -9,8 +9,14 @@
 struct DreamSettings {
     intensity: f32,
     time: f32,
+    vignette: f32,
+    grain: f32,
+    swirl: f32,
+    tint_strength: f32,
     _align: f32,
     _align2: f32,
+    tint: vec3<f32>,
+    _align3: f32,
 }
 
 @group(0) @binding(2) var<uniform> settings: DreamSettings;
@@ -29,6 +35,12 @@ fn hash1(p: vec2<f32>) -> f32 {
     return fract(sin(dot(p, vec2<f32>(127.1, 311.7))) * 43758.5453);
 }
 
+// --- Section grade ---
+
+fn apply_section_grade(color: vec3<f32>) -> vec3<f32> {
+    return mix(color, color * settings.tint, settings.tint_strength);
+}
+
 // --- Effect 1: Yellow tint ---
 
 fn apply_yellow_tint(color: vec3<f32>, intensity: f32) -> vec3<f32> {
@@ -172,6 +184,24 @@ fn swirl_pattern(uv: vec2<f32>, intensity: f32, time: f32, aspect: f32) -> f32 {
     return swirl_accum * intensity;
 }
 
+// --- Effect 5: Vignette ---
+
+fn apply_vignette(color: vec3<f32>, uv: vec2<f32>, strength: f32, aspect: f32) -> vec3<f32> {
+    let diff = uv - vec2<f32>(0.5);
+    let dist = length(vec2<f32>(diff.x * aspect, diff.y)) / length(vec2<f32>(0.5 * aspect, 0.5));
+    let darken = smoothstep(0.35, 1.0, dist) * strength;
+    return color * (1.0 - darken);
+}
+
+// --- Effect 6: Film grain ---
+
+fn apply_grain(color: vec3<f32>, uv: vec2<f32>, strength: f32, time: f32) -> vec3<f32> {
+    // Reseed every frame-ish so the grain crawls rather than sits still.
+    let seed = uv * 1000.0 + vec2<f32>(fract(time * 12.9898) * 100.0, fract(time * 78.233) * 100.0);
+    let noise = hash1(seed) - 0.5;
+    return color + vec3<f32>(noise * strength);
+}
+
 // --- Compositing ---
 
 @fragment
@@ -181,7 +211,8 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let time = settings.time;
 
     if intensity < 0.001 {
-        return textureSample(screen_texture, screen_sampler, uv);
+        let base = textureSample(screen_texture, screen_sampler, uv);
+        return vec4<f32>(apply_section_grade(base.rgb), base.a);
     }
 
     let dims = textureDimensions(screen_texture);
@@ -190,13 +221,16 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     // Staggered fade-in: effects layer in gradually
     let tint_i = smoothstep(0.0, 0.3, intensity);
     let aberr_i = smoothstep(0.1, 0.5, intensity);
-    let swirl_i = smoothstep(0.4, 1.0, intensity) * 0.7;
+    let swirl_i = smoothstep(0.4, 1.0, intensity) * 0.7 * settings.swirl;
     let eye_i = smoothstep(0.5, 1.0, intensity) * 0.7;
+    let vignette_i = smoothstep(0.2, 1.0, intensity) * settings.vignette;
+    let grain_i = smoothstep(0.1, 1.0, intensity) * settings.grain;
 
     // 1. Sample with chromatic aberration
     var color = apply_chromatic_aberration(uv, aberr_i);
 
-    // 2. Yellow tint
+    // 2. Section grade, with the chase's yellow tint layered on top
+    color = apply_section_grade(color);
     color = apply_yellow_tint(color, tint_i);
 
     // 3. Swirl tendrils (additive golden glow)
@@ -208,5 +242,11 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     let eye = eye_pattern(uv, eye_i, time, aspect);
     color = mix(color, eye.rgb, eye.a);
 
+    // 5. Vignette closes in from the frame edges
+    color = apply_vignette(color, uv, vignette_i, aspect);
+
+    // 6. Film grain
+    color = apply_grain(color, uv, grain_i, time);
+
     return vec4<f32>(color, 1.0);
 }
@@ -0,0 +1,69 @@
+// Wind sway for vegetation. Bevy's mesh vertex shader with the world position
+// pushed along the wind, more the higher the vertex sits above the model's
+// origin, so trunks and stems stay planted while crowns and tips move.
+
+#import bevy_pbr::{
+    mesh_functions,
+    forward_io::{Vertex, VertexOutput},
+    mesh_view_bindings::globals,
+    view_transformations::position_world_to_clip,
+}
+
+struct Wind {
+    direction: vec2<f32>,
+    strength: f32,
+    speed: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> wind: Wind;
+
+fn sway(world: vec3<f32>, height: f32) -> vec3<f32> {
+    // Phase by position along the wind so gusts roll across the ground rather
+    // than everything moving in lockstep.
+    let phase = dot(world.xz, wind.direction) * 0.15 + world.x * 0.05;
+    let t = globals.time * wind.speed * 6.2831853;
+    let gust = sin(t + phase) * 0.7 + sin(t * 2.3 + phase * 1.7) * 0.3;
+    // A steady lean plus the gusts, growing with height.
+    let bend = wind.strength * height * (0.4 + gust);
+    let offset = wind.direction * bend;
+    return vec3<f32>(offset.x, 0.0, offset.y);
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
+#endif
+
+#ifdef VERTEX_POSITIONS
+    out.world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4<f32>(vertex.position, 1.0));
+    let origin = world_from_local[3].xyz;
+    let height = max(out.world_position.y - origin.y, 0.0);
+    out.world_position = vec4<f32>(out.world_position.xyz + sway(origin, height), out.world_position.w);
+    out.position = position_world_to_clip(out.world_position.xyz);
+#endif
+
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_UVS_B
+    out.uv_b = vertex.uv_b;
+#endif
+
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(world_from_local, vertex.tangent, vertex.instance_index);
+#endif
+
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
+
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex.instance_index;
+#endif
+
+    return out;
+}
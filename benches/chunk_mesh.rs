@@ -0,0 +1,80 @@
+// Chunk mesh generation cost, the main per-frame terrain expense.
+
+use bevy::prelude::*;
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::hint::black_box;
+
+use eurydice::terrain::generation::{NoiseSampler, StaleRegion};
+use eurydice::terrain::{TerrainConfig, TerrainNoise, TerrainSeed, generate_chunk_mesh};
+
+/// Config, noise and sampler as the chase starts them.
+fn setup() -> (TerrainConfig, TerrainNoise, NoiseSampler) {
+    let mut world = World::new();
+    world.insert_resource(TerrainSeed::default());
+    world.insert_resource(TerrainConfig::default());
+    let noise = TerrainNoise::from_world(&mut world);
+    let config = world
+        .remove_resource::<TerrainConfig>()
+        .expect("inserted above");
+    (config, noise, NoiseSampler::default())
+}
+
+fn single_chunk(c: &mut Criterion) {
+    let (config, noise, sampler) = setup();
+    c.bench_function("single chunk", |b| {
+        b.iter(|| generate_chunk_mesh(black_box(3), black_box(-4), &config, &noise, &sampler, None))
+    });
+}
+
+/// Every chunk in the render radius, as the prewarm on entering the chase
+/// builds them.
+fn render_radius_batch(c: &mut Criterion) {
+    let (config, noise, sampler) = setup();
+    let radius = config.render_radius;
+    c.bench_function("render radius batch", |b| {
+        b.iter(|| {
+            for cz in -radius..radius {
+                for cx in -radius..radius {
+                    if cx * cx + cz * cz <= radius * radius {
+                        black_box(generate_chunk_mesh(cx, cz, &config, &noise, &sampler, None));
+                    }
+                }
+            }
+        })
+    });
+}
+
+/// A chunk next to a stale one, which samples the old noise as well and
+/// blends toward it.
+fn stale_neighbour(c: &mut Criterion) {
+    let (config, noise, sampler) = setup();
+    let (_, edge_heights) = generate_chunk_mesh(0, 0, &config, &noise, &sampler, None);
+    let stale = StaleRegion {
+        sampler,
+        grid_pos: (0, 0),
+        edge_heights,
+    };
+    let rotated = sampler.rotate_right(
+        Vec2::new(4.0, 4.0),
+        config.chunk_size,
+        config.noise_scale,
+        &mut StdRng::seed_from_u64(0),
+    );
+    c.bench_function("chunk beside stale region", |b| {
+        b.iter(|| {
+            generate_chunk_mesh(
+                black_box(1),
+                black_box(0),
+                &config,
+                &noise,
+                &rotated,
+                Some(&stale),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, single_chunk, render_radius_batch, stale_neighbour);
+criterion_main!(benches);
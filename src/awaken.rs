@@ -6,14 +6,26 @@ use bevy::window::{CursorGrabMode, CursorOptions};
 
 use crate::player::{Player, PlayerLook};
 use crate::sections::{PlotFlags, Sections};
+use crate::speedrun::{RunTimer, format_time};
+use crate::transition::AmbientFade;
 
 pub struct AwakenPlugin;
 
 impl Plugin for AwakenPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(Sections::Awaken), setup_awaken)
-            .add_systems(OnExit(Sections::Awaken), exit_awaken)
-            .add_systems(Update, awaken_timer.run_if(in_state(Sections::Awaken)));
+        // The exit timer is kept apart from the scene setup so the section flow
+        // runs without assets or a renderer.
+        app.add_systems(
+            OnEnter(Sections::Awaken),
+            (start_awaken_timer, setup_awaken),
+        )
+        .add_systems(OnExit(Sections::Awaken), exit_awaken)
+        .add_systems(
+            Update,
+            (awaken_timer, fade_to_menu)
+                .chain()
+                .run_if(in_state(Sections::Awaken)),
+        );
     }
 }
 
@@ -22,12 +34,108 @@ const NPC_PATH: &str = "character/character.gltf";
 const ALT_PATH: &str = "character/base.gltf";
 const ANIM_SITTING: usize = 26;
 const EXIT_DELAY: f32 = 5.0;
+/// Seconds the stats card stays up before returning to the menu.
+const STATS_DURATION: f32 = 6.0;
+/// Seconds the room takes to fade to black before the menu. A skip starts the
+/// fade rather than cutting.
+const EXIT_FADE: f32 = 1.0;
+/// Peak dream intensity past which the player is considered lost to it.
+const LOST_DREAM_INTENSITY: f32 = 0.95;
+/// Rotations over the chase past which the player is considered lost.
+const LOST_ROTATIONS: u32 = 12;
+
+/// Which ending the player earned, decided once from `PlotFlags` on entering
+/// the room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    /// Looked back on the stairs: the chair is empty.
+    Alone,
+    /// Followed the chevron often enough to know who they were chasing.
+    Reunited,
+    /// Barely followed the chevron: someone is there, but not who they lost.
+    Stranger,
+    /// Let the dream run too deep: they're in the chair but turned away, and
+    /// the room never quite brightens.
+    Lost,
+}
+
+impl Ending {
+    pub const ALL: [Ending; 4] = [
+        Ending::Alone,
+        Ending::Reunited,
+        Ending::Stranger,
+        Ending::Lost,
+    ];
+
+    pub fn from_flags(flags: &PlotFlags) -> Self {
+        if flags.player_looked_behind {
+            Ending::Alone
+        } else if flags.peak_dream_intensity >= LOST_DREAM_INTENSITY
+            || flags.total_rotations >= LOST_ROTATIONS
+        {
+            Ending::Lost
+        } else if flags.chevron_count > 1 {
+            Ending::Reunited
+        } else {
+            Ending::Stranger
+        }
+    }
+
+    /// Model seated in the chair, if anyone.
+    fn npc_path(self) -> Option<&'static str> {
+        match self {
+            Ending::Alone => None,
+            Ending::Reunited | Ending::Lost => Some(NPC_PATH),
+            Ending::Stranger => Some(ALT_PATH),
+        }
+    }
+
+    /// Ambient colour and brightness for the room.
+    fn ambient(self) -> (Color, f32) {
+        match self {
+            Ending::Alone | Ending::Reunited | Ending::Stranger => {
+                (Color::srgb(0.9, 0.85, 0.7), 8.0)
+            }
+            Ending::Lost => (Color::srgb(0.55, 0.55, 0.7), 2.0),
+        }
+    }
+
+    /// Intensity of the lamp over the chair.
+    fn lamp_intensity(self) -> f32 {
+        match self {
+            Ending::Alone | Ending::Reunited | Ending::Stranger => 100_000.0,
+            Ending::Lost => 25_000.0,
+        }
+    }
+
+    /// Which way the seated NPC faces. Turned toward the player unless lost.
+    fn npc_yaw(self) -> f32 {
+        match self {
+            Ending::Lost => std::f32::consts::FRAC_PI_2,
+            _ => -std::f32::consts::FRAC_PI_2,
+        }
+    }
+}
 
 #[derive(Resource)]
 struct AwakenState {
     timer: f32,
+    stats_shown: bool,
+    /// When, on `timer`, the menu takes over. Brought forward by a skip.
+    exit_at: f32,
 }
 
+impl AwakenState {
+    /// How far through the fade out the room is, 0 to 1.
+    fn exit_fade(&self) -> f32 {
+        (1.0 - (self.exit_at - self.timer) / EXIT_FADE).clamp(0.0, 1.0)
+    }
+}
+
+/// Black over everything, stats card included, that the room fades into.
+#[derive(Component)]
+struct ExitFade;
+
 #[derive(Resource)]
 struct AwakenNpcAnimation {
     graph: Handle<AnimationGraph>,
@@ -42,15 +150,14 @@ fn setup_awaken(
     mut graphs: ResMut<Assets<AnimationGraph>>,
     asset_server: Res<AssetServer>,
     flags: Res<PlotFlags>,
+    mut ambient: ResMut<AmbientFade>,
     mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
 ) {
-    commands.insert_resource(GlobalAmbientLight {
-        color: Color::srgb(0.9, 0.85, 0.7),
-        brightness: 8.0,
-        affects_lightmapped_meshes: false,
-    });
+    let ending = Ending::from_flags(&flags);
+    info!("Ending: {ending:?}");
 
-    commands.insert_resource(AwakenState { timer: 0.0 });
+    let (ambient_color, ambient_brightness) = ending.ambient();
+    ambient.fade_to(ambient_color, ambient_brightness);
 
     // Position camera facing +X
     if let Ok((mut transform, mut look)) = player.single_mut() {
@@ -77,7 +184,7 @@ fn setup_awaken(
     commands.spawn((
         PointLight {
             color: Color::srgb(1.0, 0.9, 0.7),
-            intensity: 100_000.0,
+            intensity: ending.lamp_intensity(),
             range: 30.0,
             ..default()
         },
@@ -85,14 +192,9 @@ fn setup_awaken(
         DespawnOnExit(Sections::Awaken),
     ));
 
-    // NPC in the chair, only if the player didn't look behind on the stairs
-    if !flags.player_looked_behind {
+    // NPC in the chair, unless the ending leaves it empty.
+    if let Some(path) = ending.npc_path() {
         let mut graph = AnimationGraph::new();
-        let path = if flags.chevron_count > 1 {
-            NPC_PATH
-        } else {
-            ALT_PATH
-        };
         let sitting = graph.add_clip(
             asset_server.load(GltfAssetLabel::Animation(ANIM_SITTING).from_asset(path)),
             1.0,
@@ -108,7 +210,7 @@ fn setup_awaken(
                 AwakenNpc,
                 SceneRoot(asset_server.load(GltfAssetLabel::Scene(0).from_asset(path))),
                 Transform::from_xyz(1.0, 0.0, 0.5)
-                    .with_rotation(Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2)),
+                    .with_rotation(Quat::from_rotation_y(ending.npc_yaw())),
                 DespawnOnExit(Sections::Awaken),
             ))
             .observe(start_sitting_animation);
@@ -133,21 +235,130 @@ fn start_sitting_animation(
     }
 }
 
+fn start_awaken_timer(mut commands: Commands) {
+    commands.insert_resource(AwakenState {
+        timer: 0.0,
+        stats_shown: false,
+        exit_at: EXIT_DELAY + STATS_DURATION,
+    });
+    commands.spawn((
+        ExitFade,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(200),
+        DespawnOnExit(Sections::Awaken),
+    ));
+}
+
+/// After the room has had its moment, show the run stats; leave for the menu
+/// when they time out or the player presses anything, once the fade is done.
 fn awaken_timer(
+    mut commands: Commands,
     mut state: ResMut<AwakenState>,
     time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    flags: Res<PlotFlags>,
+    run_timer: Res<RunTimer>,
     mut next_section: ResMut<NextState<Sections>>,
 ) {
     state.timer += time.delta_secs();
-    if state.timer >= EXIT_DELAY {
+    if state.timer < EXIT_DELAY {
+        return;
+    }
+    if !state.stats_shown {
+        state.stats_shown = true;
+        spawn_stats_card(&mut commands, &flags, &run_timer);
+        return;
+    }
+    let skipped =
+        keyboard.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some();
+    if skipped {
+        state.exit_at = state.exit_at.min(state.timer + EXIT_FADE);
+    }
+    if state.timer >= state.exit_at {
         next_section.set(Sections::Menu);
     }
 }
 
-fn exit_awaken(mut commands: Commands, mut cursor: Query<&mut CursorOptions>) {
+/// Darken toward the menu so the fade lands exactly on the state change.
+fn fade_to_menu(state: Res<AwakenState>, mut fade: Query<&mut BackgroundColor, With<ExitFade>>) {
+    let Ok(mut background) = fade.single_mut() else {
+        return;
+    };
+    let t = state.exit_fade();
+    background.0 = Color::srgba(0.0, 0.0, 0.0, t * t * (3.0 - 2.0 * t));
+}
+
+fn spawn_stats_card(commands: &mut Commands, flags: &PlotFlags, run_timer: &RunTimer) {
+    let lines = [
+        format!("Time: {}", format_time(run_timer.total)),
+        format!("Rotations: {}", flags.total_rotations),
+        format!(
+            "Deepest dream: {}%",
+            (flags.peak_dream_intensity * 100.0).round()
+        ),
+        format!(
+            "Looked behind: {}",
+            if flags.player_looked_behind {
+                "yes"
+            } else {
+                "no"
+            }
+        ),
+    ];
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(150),
+            DespawnOnExit(Sections::Awaken),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("{:?}", Ending::from_flags(flags))),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            for line in lines {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.8, 0.8, 0.8, 1.0)),
+                ));
+            }
+        });
+}
+
+fn exit_awaken(
+    mut commands: Commands,
+    mut ambient: ResMut<AmbientFade>,
+    mut cursor: Query<&mut CursorOptions>,
+) {
     commands.remove_resource::<AwakenState>();
     commands.remove_resource::<AwakenNpcAnimation>();
-    commands.insert_resource(GlobalAmbientLight::NONE);
+    ambient.fade_out();
 
     let Ok(mut cursor) = cursor.single_mut() else {
         return;
@@ -155,3 +366,69 @@ fn exit_awaken(mut commands: Commands, mut cursor: Query<&mut CursorOptions>) {
     cursor.grab_mode = CursorGrabMode::None;
     cursor.visible = true;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flags(looked_behind: bool, chevrons: u32, peak_dream: f32, rotations: u32) -> PlotFlags {
+        PlotFlags {
+            player_looked_behind: looked_behind,
+            chevron_count: chevrons,
+            peak_dream_intensity: peak_dream,
+            total_rotations: rotations,
+        }
+    }
+
+    #[test]
+    fn endings_follow_plot_flags() {
+        let cases = [
+            (flags(false, 0, 0.0, 0), Ending::Stranger),
+            (flags(false, 1, 0.5, 3), Ending::Stranger),
+            (flags(false, 2, 0.5, 3), Ending::Reunited),
+            (flags(false, 5, 0.0, 0), Ending::Reunited),
+            (flags(true, 0, 0.0, 0), Ending::Alone),
+            (flags(true, 5, 0.0, 0), Ending::Alone),
+            // A deep dream or many rotations outweigh the chevron...
+            (flags(false, 5, LOST_DREAM_INTENSITY, 0), Ending::Lost),
+            (flags(false, 5, 0.0, LOST_ROTATIONS), Ending::Lost),
+            (flags(false, 0, 1.0, LOST_ROTATIONS), Ending::Lost),
+            // ...but looking back still empties the chair.
+            (flags(true, 5, 1.0, LOST_ROTATIONS), Ending::Alone),
+        ];
+        for (flags, expected) in cases {
+            assert_eq!(
+                Ending::from_flags(&flags),
+                expected,
+                "looked behind {}, chevrons {}, dream {}, rotations {}",
+                flags.player_looked_behind,
+                flags.chevron_count,
+                flags.peak_dream_intensity,
+                flags.total_rotations
+            );
+        }
+    }
+
+    #[test]
+    fn awaken_timer_returns_to_menu() {
+        let mut app = crate::sections::headless_app(Sections::Awaken);
+        app.init_resource::<RunTimer>()
+            .add_systems(OnEnter(Sections::Awaken), start_awaken_timer)
+            .add_systems(
+                Update,
+                (awaken_timer, fade_to_menu)
+                    .chain()
+                    .run_if(in_state(Sections::Awaken)),
+            );
+
+        // A tenth of a second per update, with a second to spare.
+        let updates = ((EXIT_DELAY + STATS_DURATION + 1.0) * 10.0) as usize;
+        for _ in 0..updates {
+            app.update();
+        }
+        assert_eq!(
+            *app.world().resource::<State<Sections>>().get(),
+            Sections::Menu
+        );
+    }
+}
@@ -1,47 +1,198 @@
 // Chase section
+use std::f32::consts::{PI, TAU};
+
 use bevy::prelude::*;
+use bevy::transform::TransformSystems;
 
 use crate::dream::DreamSettings;
-use crate::npc::{Npc, NpcChevron};
-use crate::player::Player;
+use crate::haptics::{HapticCue, Haptics};
+use crate::npc::{IDLE_DIST, Npc, NpcChevron};
+use crate::photo::photo_mode_inactive;
+use crate::player::{Player, PlayerInput, PlayerLook, ReadInput, Stationary};
+use crate::prompt::Prompt;
 use crate::sections::{PlotFlags, Sections};
-use crate::terrain::{RotationCount, SpawnedChunks, TerrainChunk};
+use crate::settings::{AccessibilitySettings, AutoCamera, CameraSettings, Difficulty};
+use crate::terrain::RotationCount;
 
 pub struct ChasePlugin;
 
 impl Plugin for ChasePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(Sections::Chase), reset_chase_state)
+            .init_resource::<ChaseElapsed>()
+            .init_resource::<LostGuide>()
+            .init_resource::<Descent>()
             .add_systems(
                 Update,
-                (chase_dream_ramp, chase_chevron_degrade, chase_npc_vanish)
+                (
+                    chase_dream_ramp,
+                    dream_rumble,
+                    chase_chevron_degrade,
+                    chase_npc_vanish
+                        .run_if(guide_not_lost)
+                        .run_if(not_descending),
+                    toggle_auto_camera,
+                    auto_camera
+                        .run_if(|auto: Res<AutoCamera>| auto.0)
+                        .run_if(not_descending),
+                    advance_descent,
+                    update_follow_nudge,
+                    chase_sun,
+                    check_lost_guide
+                        .run_if(|accessibility: Res<AccessibilitySettings>| {
+                            !accessibility.no_soft_fail
+                        })
+                        .run_if(not_descending),
+                )
                     .chain()
-                    .run_if(in_state(Sections::Chase)),
+                    .after(ReadInput)
+                    .run_if(in_state(Sections::Chase))
+                    .run_if(photo_mode_inactive),
+            )
+            // The sink is layered on like head-bob: removed before gameplay moves
+            // the player and re-applied just before transforms propagate.
+            .add_systems(PreUpdate, remove_descent_sink)
+            .add_systems(
+                PostUpdate,
+                apply_descent_sink
+                    .run_if(in_state(Sections::Chase))
+                    .before(TransformSystems::Propagate),
             )
             .add_systems(OnExit(Sections::Chase), exit_chase);
     }
 }
 
-fn reset_chase_state(mut plot_flags: ResMut<PlotFlags>, mut rotation_count: ResMut<RotationCount>) {
+/// Everything a run starts from, in one place. OnEnter runs in the state
+/// transition, before Update, so the ramp's first frame always sees these.
+fn reset_chase_state(
+    mut dream: Query<&mut DreamSettings>,
+    mut plot_flags: ResMut<PlotFlags>,
+    mut rotation_count: ResMut<RotationCount>,
+    mut stationary: ResMut<Stationary>,
+    mut lost: ResMut<LostGuide>,
+    mut descent: ResMut<Descent>,
+    mut elapsed: ResMut<ChaseElapsed>,
+) {
+    if let Ok(mut settings) = dream.single_mut() {
+        settings.intensity = 0.0;
+    }
     *plot_flags = PlotFlags::default();
+    *lost = LostGuide::default();
+    *descent = Descent::default();
+    elapsed.0 = 0.0;
     rotation_count.0 = 0;
+    stationary.0 = 0.0;
 }
 
+/// Seconds of chase play so far, paused with the chase.
+#[derive(Resource, Default)]
+struct ChaseElapsed(f32);
+
 /// Base dream intensity increase per second.
 const DREAM_BASE_RATE: f32 = 0.005;
 /// Multiplier when the NPC chevron is visible (NPC is far away).
 const DREAM_CHEVRON_MULTIPLIER: f32 = 2.0;
 /// Flat intensity bump per terrain rotation.
 const DREAM_ROTATION_BUMP: f32 = 0.03;
+/// Dream intensity at which the controller starts to rumble, rising to
+/// `DREAM_RUMBLE` at full intensity.
+const DREAM_RUMBLE_START: f32 = 0.6;
+const DREAM_RUMBLE: HapticCue = HapticCue {
+    strong: 0.5,
+    weak: 0.8,
+    duration: 0.0,
+};
 /// Dream intensity at which the chevron turns red and NPC can vanish.
 const CHEVRON_RED_THRESHOLD: f32 = 0.7;
 /// Max chevron shake offset in pixels at full intensity.
 const CHEVRON_MAX_SHAKE: f32 = 8.0;
+/// New shake targets per second. The noise glides between them, so the
+/// jitter looks the same at any frame rate.
+const CHEVRON_SHAKE_RATE: f32 = 14.0;
+/// Extra scale at the peak of the high-contrast danger pulse.
+const CHEVRON_PULSE: f32 = 0.35;
+/// Danger pulse speed in radians per second.
+const CHEVRON_PULSE_RATE: f32 = 6.0;
+
+/// Seconds standing still before the player is nudged to follow.
+const NUDGE_DELAY: f32 = 4.0;
+
+/// Sun pitch at the start of the chase, matching `spawn_chase_light`.
+const SUN_HIGH_PITCH: f32 = -1.0;
+/// Sun pitch at full dream intensity, low enough for long shadows.
+const SUN_LOW_PITCH: f32 = -0.2;
+const SUN_YAW: f32 = 0.5;
+const SUN_HIGH_ILLUMINANCE: f32 = 10_000.0;
+const SUN_LOW_ILLUMINANCE: f32 = 5_000.0;
+const SUN_LOW_COLOR: Color = Color::srgb(1.0, 0.55, 0.35);
+
+/// Seconds the NPC may stay out past its idle distance before it is lost.
+const LOST_FAR_TIME: f32 = 10.0;
+/// Seconds standing still before the NPC is lost.
+const LOST_STATIONARY_TIME: f32 = 45.0;
+/// Length of the "lost" beat before the chase restarts.
+const LOST_BEAT_DURATION: f32 = 3.0;
+/// Seconds for the lost beat to fade to black.
+const LOST_FADE: f32 = 1.5;
+
+/// Tracks how long the player has been losing the NPC, and the beat once
+/// they have.
+#[derive(Resource, Default)]
+struct LostGuide {
+    far_for: f32,
+    /// Seconds into the lost beat, once it has started.
+    beat: Option<f32>,
+}
+
+/// Full-screen fade and text shown when the NPC is lost.
+#[derive(Component)]
+struct LostOverlay;
+
+pub(crate) const AUTO_CAMERA_KEY: KeyCode = KeyCode::KeyV;
+/// Seconds after the last mouse movement before the auto camera eases back in.
+const AUTO_CAMERA_SETTLE: f32 = 2.0;
+/// How quickly the auto camera turns toward the NPC once fully engaged.
+const AUTO_CAMERA_RATE: f32 = 1.5;
+/// Aim this far above the NPC's feet.
+const AUTO_CAMERA_AIM_HEIGHT: f32 = 1.2;
+
+/// Seconds the camera sinks and tilts before the cut to the underworld.
+const DESCENT_DURATION: f32 = 1.2;
+/// How far the camera sinks into the ground by the end of the descent.
+const DESCENT_DEPTH: f32 = 1.2;
+/// How quickly the view is pulled down toward the ground.
+const DESCENT_TILT_RATE: f32 = 3.0;
+
+/// The scripted fall into the ground between the vanish and the underworld.
+#[derive(Resource, Default)]
+struct Descent {
+    /// Seconds into the descent, once it has started.
+    elapsed: Option<f32>,
+    /// Sink applied to the camera this frame, taken back off in PreUpdate.
+    applied: f32,
+}
+
+/// Fades to black over the descent; the underworld's title card takes over
+/// once it is opaque.
+#[derive(Component)]
+struct DescentOverlay;
+
+/// Nudge the player to follow once they have stood still for a while.
+fn update_follow_nudge(stationary: Res<Stationary>, mut prompt: ResMut<Prompt>) {
+    if stationary.0 > NUDGE_DELAY {
+        prompt.show("Follow them");
+    } else {
+        prompt.clear();
+    }
+}
 
 fn chase_dream_ramp(
     mut dream_query: Query<&mut DreamSettings>,
     chevron_query: Query<&Visibility, With<NpcChevron>>,
     mut rotation_count: ResMut<RotationCount>,
+    mut plot_flags: ResMut<PlotFlags>,
+    mut elapsed: ResMut<ChaseElapsed>,
+    difficulty: Res<Difficulty>,
     time: Res<Time>,
 ) {
     let Ok(mut settings) = dream_query.single_mut() else {
@@ -49,7 +200,16 @@ fn chase_dream_ramp(
     };
 
     let dt = time.delta_secs();
-    let mut rate = DREAM_BASE_RATE;
+    elapsed.0 += dt;
+    // A calm opening: rotations still count toward the ending, but nothing
+    // feeds the dream until the onset has passed.
+    if elapsed.0 < difficulty.dream_onset {
+        plot_flags.total_rotations += rotation_count.0;
+        rotation_count.0 = 0;
+        return;
+    }
+
+    let mut rate = DREAM_BASE_RATE * difficulty.dream_rate_mul;
 
     // Faster when the chevron is visible (NPC is far enough to show it).
     if let Ok(visibility) = chevron_query.single() {
@@ -64,20 +224,56 @@ fn chase_dream_ramp(
     let rotations = rotation_count.0;
     if rotations > 0 {
         settings.intensity += DREAM_ROTATION_BUMP * rotations as f32;
+        plot_flags.total_rotations += rotations;
         rotation_count.0 = 0;
     }
 
     settings.intensity = settings.intensity.min(1.0);
+    plot_flags.peak_dream_intensity = plot_flags.peak_dream_intensity.max(settings.intensity);
+}
+
+/// Build the rumble as the dream closes in, quadratic so it only really
+/// bites near the end.
+fn dream_rumble(dream: Query<&DreamSettings>, mut haptics: ResMut<Haptics>) {
+    let Ok(settings) = dream.single() else {
+        return;
+    };
+    let rise = ((settings.intensity - DREAM_RUMBLE_START) / (1.0 - DREAM_RUMBLE_START)).max(0.0);
+    if rise > 0.0 {
+        haptics.hold(DREAM_RUMBLE.scaled(rise * rise));
+    }
+}
+
+/// Smooth 1D value noise in [-1, 1]: a hashed value at each whole `t`, eased
+/// between. `seed` picks an unrelated sequence.
+fn value_noise(t: f32, seed: f32) -> f32 {
+    let hash = |i: f32| ((i * 12.9898 + seed * 78.233).sin() * 43758.545).fract() * 2.0 - 1.0;
+    let i = t.floor();
+    let f = t - i;
+    let eased = f * f * (3.0 - 2.0 * f);
+    hash(i).lerp(hash(i + 1.0), eased)
 }
 
 fn chase_chevron_degrade(
-    mut chevron_query: Query<(&mut Node, &mut TextColor, &Visibility), With<NpcChevron>>,
+    mut chevron_query: Query<
+        (
+            &mut Text,
+            &mut TextColor,
+            &mut BackgroundColor,
+            &mut UiTransform,
+            &Visibility,
+        ),
+        With<NpcChevron>,
+    >,
     dream_query: Query<&DreamSettings>,
+    accessibility: Res<AccessibilitySettings>,
 ) {
     let Ok(settings) = dream_query.single() else {
         return;
     };
-    let Ok((mut node, mut color, visibility)) = chevron_query.single_mut() else {
+    let Ok((mut text, mut color, mut background, mut ui_transform, visibility)) =
+        chevron_query.single_mut()
+    else {
         return;
     };
 
@@ -86,35 +282,58 @@ fn chase_chevron_degrade(
         return;
     }
 
-    // Apply random shake proportional to intensity.
-    if settings.intensity > 0.1 {
-        let shake = settings.intensity * CHEVRON_MAX_SHAKE;
-        // Use time-based pseudo-random offset (changes every frame).
-        let t = settings.time * 60.0;
-        let offset_x = (t.sin() * 1.7 + (t * 2.3).cos()) * shake;
-        let offset_y = ((t * 1.3).cos() + (t * 3.1).sin()) * shake;
+    // Shake proportional to intensity, as a translation set fresh each frame
+    // so it sits on top of the placement instead of accumulating in it.
+    let offset = if settings.intensity > 0.1 && !accessibility.reduced_motion {
+        let t = settings.time * CHEVRON_SHAKE_RATE;
+        Vec2::new(value_noise(t, 0.0), value_noise(t, 17.0))
+            * settings.intensity
+            * CHEVRON_MAX_SHAKE
+    } else {
+        Vec2::ZERO
+    };
+    ui_transform.translation = Val2::px(offset.x, offset.y);
+
+    let danger = settings.intensity >= CHEVRON_RED_THRESHOLD;
 
-        // Offset the existing position.
-        if let Val::Px(ref mut left) = node.left {
-            *left += offset_x;
+    if accessibility.high_contrast_chevron {
+        // Encode danger by shape, fill and pulse as well as colour: an outlined
+        // "v" becomes a filled, pulsing "V".
+        let glyph = if danger { "V" } else { "v" };
+        if text.0 != glyph {
+            text.0 = glyph.into();
         }
-        if let Val::Px(ref mut top) = node.top {
-            *top += offset_y;
+        if danger {
+            color.0 = Color::BLACK;
+            background.0 = Color::WHITE;
+            let pulse = (settings.time * CHEVRON_PULSE_RATE).sin().abs();
+            ui_transform.scale = Vec2::splat(1.0 + CHEVRON_PULSE * pulse);
+        } else {
+            color.0 = Color::WHITE;
+            background.0 = Color::srgba(0.0, 0.0, 0.0, 0.8);
+            ui_transform.scale = Vec2::ONE;
         }
+        return;
     }
 
+    background.0 = Color::NONE;
+    ui_transform.scale = Vec2::ONE;
+
     // Turn red above threshold.
-    if settings.intensity >= CHEVRON_RED_THRESHOLD {
+    if danger {
         color.0 = Color::linear_rgb(1.0, 0.0, 0.0);
     }
 }
 
+/// Once the dream is deep enough, the NPC vanishes the moment it passes behind
+/// the camera and the chase hands over to the underworld. Reaching full
+/// intensity forces the handover for players who never turn away from it.
 fn chase_npc_vanish(
     mut commands: Commands,
     npc_query: Query<(Entity, &GlobalTransform), With<Npc>>,
     camera_query: Query<&GlobalTransform, With<Player>>,
     dream_query: Query<&DreamSettings>,
-    mut next_state: ResMut<NextState<Sections>>,
+    mut descent: ResMut<Descent>,
 ) {
     let Ok(settings) = dream_query.single() else {
         return;
@@ -122,43 +341,253 @@ fn chase_npc_vanish(
     if settings.intensity < CHEVRON_RED_THRESHOLD {
         return;
     };
+
+    if let (Ok((npc_entity, npc_global)), Ok(camera_global)) =
+        (npc_query.single(), camera_query.single())
+    {
+        // In Bevy's view space, camera looks down -Z, so z >= 0 means behind.
+        let view_matrix = camera_global.affine().inverse();
+        let npc_view = view_matrix.transform_point3(npc_global.translation());
+        if npc_view.z >= 0.0 {
+            commands.entity(npc_entity).despawn();
+            begin_descent(&mut commands, &mut descent);
+            return;
+        }
+    }
+
+    // Fallback: the NPC stays in view and exit_chase despawns it.
     if settings.intensity >= 1.0 {
+        begin_descent(&mut commands, &mut descent);
+    }
+}
+
+fn toggle_auto_camera(keyboard: Res<ButtonInput<KeyCode>>, mut auto: ResMut<AutoCamera>) {
+    if keyboard.just_pressed(AUTO_CAMERA_KEY) {
+        auto.0 = !auto.0;
+    }
+}
+
+/// Ease the view toward the NPC. Any mouse movement hands control back, and
+/// the pull fades in again once the mouse has been still for a while.
+fn auto_camera(
+    time: Res<Time>,
+    input: Res<PlayerInput>,
+    mut since_mouse: Local<f32>,
+    npc: Query<&Transform, (With<Npc>, Without<Player>)>,
+    mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+    camera: Res<CameraSettings>,
+) {
+    let dt = time.delta_secs();
+    if input.look != Vec2::ZERO {
+        *since_mouse = 0.0;
+    } else {
+        *since_mouse += dt;
+    }
+    let weight = (*since_mouse / AUTO_CAMERA_SETTLE - 1.0).clamp(0.0, 1.0);
+    if weight == 0.0 {
+        return;
+    }
+    let (Ok(npc), Ok((mut transform, mut look))) = (npc.single(), player.single_mut()) else {
+        return;
+    };
+
+    let to_npc = npc.translation + Vec3::Y * AUTO_CAMERA_AIM_HEIGHT - transform.translation;
+    let target_yaw = (-to_npc.x).atan2(-to_npc.z);
+    let target_pitch = to_npc
+        .y
+        .atan2(to_npc.xz().length())
+        .clamp(-camera.max_pitch(), camera.max_pitch());
+
+    let blend = (1.0 - (-AUTO_CAMERA_RATE * dt).exp()) * weight;
+    // Turn the short way round.
+    let yaw_delta = (target_yaw - look.yaw + PI).rem_euclid(TAU) - PI;
+    look.yaw += yaw_delta * blend;
+    look.pitch = look.pitch.lerp(target_pitch, blend);
+    transform.rotation = Quat::from_rotation_y(look.yaw) * Quat::from_rotation_x(look.pitch);
+}
+
+fn not_descending(descent: Res<Descent>) -> bool {
+    descent.elapsed.is_none()
+}
+
+/// Start the descent. Only called while it isn't running, so the underworld is
+/// requested exactly once, at its end.
+fn begin_descent(commands: &mut Commands, descent: &mut Descent) {
+    descent.elapsed = Some(0.0);
+    commands.spawn((
+        DescentOverlay,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(90),
+        Pickable::IGNORE,
+        DespawnOnExit(Sections::Chase),
+    ));
+}
+
+/// Pull the view toward the ground and fade out, then hand over to the
+/// underworld behind a fully black screen.
+fn advance_descent(
+    time: Res<Time>,
+    mut descent: ResMut<Descent>,
+    mut next_state: ResMut<NextState<Sections>>,
+    mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+    mut overlay: Query<&mut BackgroundColor, With<DescentOverlay>>,
+    camera: Res<CameraSettings>,
+) {
+    let Some(elapsed) = descent.elapsed.as_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    *elapsed += dt;
+    let t = (*elapsed / DESCENT_DURATION).min(1.0);
+
+    if let Ok((mut transform, mut look)) = player.single_mut() {
+        let blend = 1.0 - (-DESCENT_TILT_RATE * dt).exp();
+        look.pitch = look.pitch.lerp(-camera.max_pitch(), blend);
+        transform.rotation = Quat::from_rotation_y(look.yaw) * Quat::from_rotation_x(look.pitch);
+    }
+    for mut background in &mut overlay {
+        background.0.set_alpha(t * t);
+    }
+    if t >= 1.0 {
         next_state.set(Sections::Underworld);
     }
+}
+
+fn remove_descent_sink(
+    mut descent: ResMut<Descent>,
+    mut player: Query<&mut Transform, With<Player>>,
+) {
+    if descent.applied == 0.0 {
+        return;
+    }
+    if let Ok(mut transform) = player.single_mut() {
+        transform.translation.y += descent.applied;
+    }
+    descent.applied = 0.0;
+}
 
-    let Ok((npc_entity, npc_global)) = npc_query.single() else {
+fn apply_descent_sink(
+    mut descent: ResMut<Descent>,
+    mut player: Query<&mut Transform, With<Player>>,
+) {
+    let Some(elapsed) = descent.elapsed else {
         return;
     };
-    let Ok(camera_global) = camera_query.single() else {
+    let Ok(mut transform) = player.single_mut() else {
         return;
     };
+    let t = (elapsed / DESCENT_DURATION).min(1.0);
+    // Ease in, as if the ground gives way.
+    let sink = DESCENT_DEPTH * t * t;
+    transform.translation.y -= sink;
+    descent.applied = sink;
+}
 
-    // Check if NPC is behind the camera.
-    let npc_world = npc_global.translation();
-    let view_matrix = camera_global.affine().inverse();
-    let npc_view = view_matrix.transform_point3(npc_world);
+/// Lower and redden the sun as the dream deepens.
+fn chase_sun(
+    dream_query: Query<&DreamSettings>,
+    mut sun: Query<(&mut Transform, &mut DirectionalLight)>,
+) {
+    let Ok(settings) = dream_query.single() else {
+        return;
+    };
+    let t = settings.intensity.clamp(0.0, 1.0);
+    let t = t * t * (3.0 - 2.0 * t);
+    for (mut transform, mut light) in &mut sun {
+        let pitch = SUN_HIGH_PITCH.lerp(SUN_LOW_PITCH, t);
+        transform.rotation = Quat::from_euler(EulerRot::XYZ, pitch, SUN_YAW, 0.0);
+        light.illuminance = SUN_HIGH_ILLUMINANCE.lerp(SUN_LOW_ILLUMINANCE, t);
+        light.color = Color::WHITE.mix(&SUN_LOW_COLOR, t);
+    }
+}
 
-    // In Bevy's view space, camera looks down -Z, so npc_view.z >= 0 means behind.
-    if npc_view.z >= 0.0 {
-        commands.entity(npc_entity).despawn();
-        next_state.set(Sections::Underworld);
+fn guide_not_lost(lost: Res<LostGuide>) -> bool {
+    lost.beat.is_none()
+}
+
+/// Count time spent far from the NPC or standing still. Past either limit,
+/// fade out on a short beat and re-enter the chase from the start.
+fn check_lost_guide(
+    mut commands: Commands,
+    time: Res<Time>,
+    stationary: Res<Stationary>,
+    mut lost: ResMut<LostGuide>,
+    mut next_state: ResMut<NextState<Sections>>,
+    npc: Query<&Transform, With<Npc>>,
+    player: Query<&Transform, With<Player>>,
+    mut overlay: Query<&mut BackgroundColor, With<LostOverlay>>,
+) {
+    let dt = time.delta_secs();
+
+    if let Some(beat) = lost.beat.as_mut() {
+        *beat += dt;
+        let alpha = (*beat / LOST_FADE).min(1.0);
+        for mut background in &mut overlay {
+            background.0.set_alpha(alpha);
+        }
+        if *beat >= LOST_BEAT_DURATION {
+            // Re-entering the same state runs exit_chase and the OnEnter
+            // setup again, resetting the dream and the NPC.
+            next_state.set(Sections::Chase);
+        }
+        return;
     }
+
+    let (Ok(npc), Ok(player)) = (npc.single(), player.single()) else {
+        return;
+    };
+    if npc.translation.xz().distance(player.translation.xz()) > IDLE_DIST {
+        lost.far_for += dt;
+    } else {
+        lost.far_for = 0.0;
+    }
+    if lost.far_for < LOST_FAR_TIME && stationary.0 < LOST_STATIONARY_TIME {
+        return;
+    }
+
+    lost.beat = Some(0.0);
+    commands
+        .spawn((
+            LostOverlay,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.0)),
+            GlobalZIndex(90),
+            Pickable::IGNORE,
+            DespawnOnExit(Sections::Chase),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("You lost them"),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
 }
 
 fn exit_chase(
     mut commands: Commands,
-    chunks: Query<Entity, With<TerrainChunk>>,
     npc: Query<Entity, With<Npc>>,
     lights: Query<Entity, With<DirectionalLight>>,
-    mut chevron: Query<&mut Visibility, With<NpcChevron>>,
+    mut chevron: Query<(&mut Visibility, &mut UiTransform), With<NpcChevron>>,
     mut dream: Query<&mut DreamSettings>,
-    mut spawned: ResMut<SpawnedChunks>,
+    mut plot_flags: ResMut<PlotFlags>,
 ) {
-    for entity in &chunks {
-        commands.entity(entity).despawn();
-    }
-    spawned.0.clear();
-
     if let Ok(entity) = npc.single() {
         commands.entity(entity).despawn();
     }
@@ -167,11 +596,49 @@ fn exit_chase(
         commands.entity(entity).despawn();
     }
 
-    if let Ok(mut vis) = chevron.single_mut() {
+    if let Ok((mut vis, mut ui_transform)) = chevron.single_mut() {
         *vis = Visibility::Hidden;
+        // Drop any leftover shake before the stairs reuse the chevron.
+        ui_transform.translation = Val2::ZERO;
     }
 
     if let Ok(mut settings) = dream.single_mut() {
+        plot_flags.peak_dream_intensity = plot_flags.peak_dream_intensity.max(settings.intensity);
         settings.intensity = 0.0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entering_chase_starts_at_zero_intensity() {
+        let mut app = crate::sections::headless_app(Sections::Menu);
+        app.init_resource::<RotationCount>()
+            .init_resource::<Stationary>()
+            .init_resource::<LostGuide>()
+            .init_resource::<Descent>()
+            .init_resource::<ChaseElapsed>()
+            .add_systems(OnEnter(Sections::Chase), reset_chase_state);
+        // A previous run left the dream deep and its shader clock running.
+        let camera = app
+            .world_mut()
+            .spawn(DreamSettings {
+                intensity: 0.9,
+                time: 42.0,
+                ..default()
+            })
+            .id();
+
+        app.world_mut()
+            .resource_mut::<NextState<Sections>>()
+            .set(Sections::Chase);
+        app.update();
+
+        let settings = app.world().get::<DreamSettings>(camera).unwrap();
+        assert_eq!(settings.intensity, 0.0);
+        // The clock only drives animation, so it carries on.
+        assert_eq!(settings.time, 42.0);
+    }
+}
@@ -0,0 +1,77 @@
+// Off-screen chevron placement shared by the chase and the stairs: where on
+// screen a marker for a world point goes, and which way it points.
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+/// Closest the chevron's centre comes to the viewport edge, in logical pixels.
+const CHEVRON_MARGIN: f32 = 40.0;
+/// Half the chevron node's size, to turn a centre into a top-left corner.
+const CHEVRON_HALF_SIZE: f32 = 16.0;
+
+/// Where a chevron for a world point sits on screen.
+pub struct ChevronPlacement {
+    /// Centre of the chevron in logical pixels, kept inside the margin.
+    pub screen_pos: Vec2,
+    /// Turns the chevron, which points up by default, toward the target.
+    pub rotation: Rot2,
+    /// Whether the target is in front of the camera. Behind it, the position
+    /// is only a direction pinned to the screen edge.
+    pub in_front: bool,
+}
+
+impl ChevronPlacement {
+    pub fn apply(&self, node: &mut Node, ui_transform: &mut UiTransform) {
+        node.left = Val::Px(self.screen_pos.x - CHEVRON_HALF_SIZE);
+        node.top = Val::Px(self.screen_pos.y - CHEVRON_HALF_SIZE);
+        ui_transform.rotation = self.rotation;
+    }
+}
+
+/// Place a chevron for `target`. In front of the camera it follows the
+/// projected point; behind, the direction is flipped onto the screen and the
+/// chevron pinned to the edge that way. `None` without a viewport.
+pub fn point_chevron_at(
+    target: Vec3,
+    camera: &Camera,
+    camera_global: &GlobalTransform,
+) -> Option<ChevronPlacement> {
+    let viewport_size = camera.logical_viewport_size()?;
+    let center = viewport_size / 2.0;
+
+    // In Bevy's view space the camera looks down -Z, so z < 0 is in front.
+    let target_view = camera_global.affine().inverse().transform_point3(target);
+    let in_front = target_view.z < 0.0;
+    let projected = if in_front {
+        camera
+            .world_to_viewport(camera_global, target)
+            .unwrap_or(center)
+    } else {
+        // Far enough along the flipped direction to be off screen at any size.
+        Vec2::new(target_view.x, target_view.y).normalize_or_zero() * viewport_size.length()
+            + center
+    };
+
+    let dir = (projected - center).normalize_or_zero();
+    Some(ChevronPlacement {
+        screen_pos: clamp_to_viewport(projected, viewport_size, CHEVRON_MARGIN),
+        rotation: Rot2::radians(dir.y.atan2(dir.x) - FRAC_PI_2),
+        in_front,
+    })
+}
+
+/// Pull an off-screen point back along the line from the viewport centre until
+/// it sits `margin` inside the edge, so a chevron keeps pointing the true way
+/// at any aspect ratio. Points already inside are left alone.
+fn clamp_to_viewport(pos: Vec2, viewport_size: Vec2, margin: f32) -> Vec2 {
+    let center = viewport_size / 2.0;
+    let half = (center - Vec2::splat(margin)).max(Vec2::ZERO);
+    let offset = pos - center;
+    // How far past the inset rectangle the point is along each axis; the
+    // larger of the two decides which edge the ray meets first.
+    let overshoot = (offset.abs() / half).max_element();
+    if overshoot.is_nan() || overshoot <= 1.0 {
+        return pos.clamp(center - half, center + half);
+    }
+    center + offset / overshoot
+}
@@ -0,0 +1,117 @@
+// Compass showing which way the terrain's visible axis lies relative to the player.
+use bevy::prelude::*;
+
+use crate::player::Player;
+use crate::sections::Sections;
+use crate::terrain::generation::{NoiseSampler, VisibleAxis};
+
+pub struct CompassPlugin;
+
+impl Plugin for CompassPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Sections::Chase), spawn_compass)
+            .add_systems(Update, update_compass.run_if(in_state(Sections::Chase)));
+    }
+}
+
+const COMPASS_SIZE: f32 = 64.0;
+const COMPASS_MARGIN: f32 = 16.0;
+
+#[derive(Component)]
+struct CompassNeedle;
+
+#[derive(Component)]
+struct CompassLabel;
+
+fn spawn_compass(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(COMPASS_MARGIN),
+                bottom: Val::Px(COMPASS_MARGIN),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            DespawnOnExit(Sections::Chase),
+        ))
+        .with_children(|parent| {
+            // Dial with the needle centred inside.
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(COMPASS_SIZE),
+                        height: Val::Px(COMPASS_SIZE),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.3)),
+                ))
+                .with_children(|dial| {
+                    dial.spawn((
+                        CompassNeedle,
+                        Text::new("^"),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+            parent.spawn((
+                CompassLabel,
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+            ));
+        });
+}
+
+/// Point the needle at the visible axis, with the top of the dial as the
+/// player's forward, and name the axis beneath it.
+fn update_compass(
+    sampler: Res<NoiseSampler>,
+    player: Query<&Transform, With<Player>>,
+    mut needle: Query<&mut UiTransform, With<CompassNeedle>>,
+    mut label: Query<&mut Text, With<CompassLabel>>,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+
+    let forward = transform.forward();
+    let forward_2d = Vec2::new(forward.x, forward.z).normalize_or_zero();
+    let right_2d = Vec2::new(-forward_2d.y, forward_2d.x);
+    let visible = sampler.visible_axis.dir_2d();
+
+    // Clockwise on screen from the top of the dial.
+    let angle = visible.dot(right_2d).atan2(visible.dot(forward_2d));
+    if let Ok(mut needle_transform) = needle.single_mut() {
+        needle_transform.rotation = Rot2::radians(angle);
+    }
+
+    if let Ok(mut text) = label.single_mut() {
+        let name = axis_name(sampler.visible_axis);
+        if text.0 != name {
+            text.0 = name.into();
+        }
+    }
+}
+
+fn axis_name(axis: VisibleAxis) -> &'static str {
+    match axis {
+        VisibleAxis::North => "N",
+        VisibleAxis::East => "E",
+        VisibleAxis::South => "S",
+        VisibleAxis::West => "W",
+    }
+}
@@ -0,0 +1,471 @@
+// Debug-only shortcuts for jumping between sections, setting plot flags and
+// cycling the terrain noise basis, plus a gizmo overlay and an overhead minimap
+// for the terrain sampler, an automatic flythrough and a check for leaked
+// duplicates of entities the game expects exactly one of.
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dream::DreamTimeFrozen;
+use crate::npc::{Npc, NpcChevron};
+use crate::photo::photo_mode_inactive;
+use crate::player::{MOVE_SPEED, Player, PlayerLook};
+use crate::sections::{PlotFlags, Sections};
+use crate::terrain::generation::NoiseSampler;
+use crate::terrain::{SpawnedChunks, StaleChunk, TerrainConfig, TerrainSeed};
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TerrainGizmos>()
+            .init_resource::<Minimap>()
+            .init_resource::<Flythrough>()
+            .add_systems(
+                Update,
+                (
+                    jump_to_section,
+                    toggle_plot_flags,
+                    toggle_dream_freeze,
+                    cycle_noise_type,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_terrain_gizmos,
+                    draw_terrain_gizmos.run_if(|gizmos: Res<TerrainGizmos>| gizmos.0),
+                )
+                    .chain()
+                    .run_if(in_state(Sections::Chase)),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_minimap,
+                    sync_minimap,
+                    draw_minimap.run_if(|minimap: Res<Minimap>| minimap.0),
+                )
+                    .chain()
+                    .run_if(in_state(Sections::Chase)),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_flythrough,
+                    fly_player
+                        .run_if(flythrough_active)
+                        .run_if(photo_mode_inactive),
+                )
+                    .chain()
+                    .run_if(in_state(Sections::Chase)),
+            )
+            .add_systems(OnExit(Sections::Chase), stop_flythrough)
+            .add_systems(
+                Last,
+                (
+                    warn_duplicates::<Player>,
+                    warn_duplicates::<Npc>,
+                    warn_duplicates::<NpcChevron>,
+                ),
+            );
+    }
+}
+
+/// Whether the terrain gizmo overlay is drawn.
+#[derive(Resource, Default)]
+struct TerrainGizmos(bool);
+
+/// Whether the overhead chunk minimap (J) is shown.
+#[derive(Resource, Default)]
+struct Minimap(bool);
+
+#[derive(Component)]
+struct MinimapRoot;
+
+/// One grid cell, as a chunk offset from the player's chunk.
+#[derive(Component)]
+struct MinimapCell {
+    dx: i32,
+    dz: i32,
+}
+
+#[derive(Component)]
+struct MinimapAxisLabel;
+
+/// Autopilot that walks the player along a seeded wandering path.
+#[derive(Resource, Default)]
+pub struct Flythrough {
+    active: bool,
+    elapsed: f32,
+    /// Phase offsets for each heading octave, drawn from the terrain seed so
+    /// the same seed always flies the same path.
+    phases: [f32; FLY_OCTAVES.len()],
+}
+
+/// Heading wander as (frequency in rad/s, amplitude in radians). Slow and
+/// wide so the path sweeps across quadrants and forces rotations.
+const FLY_OCTAVES: [(f32, f32); 3] = [(0.05, 2.0), (0.13, 0.8), (0.31, 0.3)];
+
+/// Chunks drawn in each direction around the player in the grid overlay.
+const GIZMO_GRID_CHUNKS: i32 = 6;
+
+/// Chunks shown in each direction around the player on the minimap. Covers the
+/// largest render radius.
+const MINIMAP_CHUNKS: i32 = 16;
+const MINIMAP_CELL_PX: f32 = 5.0;
+/// Spawned chunks by quadrant around the quadrant origin: (+x, +z), (-x, +z),
+/// (-x, -z), (+x, -z).
+const MINIMAP_QUADRANT_COLOURS: [Color; 4] = [
+    Color::srgba(0.3, 0.6, 0.3, 0.8),
+    Color::srgba(0.3, 0.4, 0.7, 0.8),
+    Color::srgba(0.6, 0.5, 0.3, 0.8),
+    Color::srgba(0.6, 0.3, 0.5, 0.8),
+];
+
+const SECTION_KEYS: [(KeyCode, Sections); 5] = [
+    (KeyCode::Digit1, Sections::Menu),
+    (KeyCode::Digit2, Sections::Chase),
+    (KeyCode::Digit3, Sections::Underworld),
+    (KeyCode::Digit4, Sections::Stairs),
+    (KeyCode::Digit5, Sections::Awaken),
+];
+
+/// Number keys 1-5 jump straight to a section via the normal state transition,
+/// so the usual OnExit/OnEnter setup runs.
+fn jump_to_section(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<Sections>>,
+) {
+    for (key, section) in SECTION_KEYS {
+        if keyboard.just_pressed(key) {
+            info!("Debug jump to {section:?}");
+            next_state.set(section);
+        }
+    }
+}
+
+/// Audition the other noise bases without a rebuild. Chunks respawn with it.
+fn cycle_noise_type(keyboard: Res<ButtonInput<KeyCode>>, mut config: ResMut<TerrainConfig>) {
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        config.noise_type = config.noise_type.next();
+        info!("Terrain noise: {:?}", config.noise_type);
+    }
+}
+
+/// L toggles `player_looked_behind`; C bumps `chevron_count`; K toggles a
+/// maxed-out `peak_dream_intensity`. Together these reach every awaken ending
+/// without replaying the earlier sections.
+fn toggle_plot_flags(keyboard: Res<ButtonInput<KeyCode>>, mut flags: ResMut<PlotFlags>) {
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        flags.player_looked_behind = !flags.player_looked_behind;
+        info!("player_looked_behind = {}", flags.player_looked_behind);
+    }
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        flags.chevron_count += 1;
+        info!("chevron_count = {}", flags.chevron_count);
+    }
+    if keyboard.just_pressed(KeyCode::KeyK) {
+        flags.peak_dream_intensity = if flags.peak_dream_intensity < 1.0 {
+            1.0
+        } else {
+            0.0
+        };
+        info!("peak_dream_intensity = {}", flags.peak_dream_intensity);
+    }
+}
+
+/// H holds the dream shader's animation on the current frame.
+fn toggle_dream_freeze(keyboard: Res<ButtonInput<KeyCode>>, mut frozen: ResMut<DreamTimeFrozen>) {
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        frozen.0 = !frozen.0;
+        info!("dream time frozen = {}", frozen.0);
+    }
+}
+
+/// Run condition for systems that should yield to the flythrough.
+pub fn flythrough_inactive(flythrough: Res<Flythrough>) -> bool {
+    !flythrough.active
+}
+
+fn flythrough_active(flythrough: Res<Flythrough>) -> bool {
+    flythrough.active
+}
+
+/// F starts or stops the flythrough. Each start replays the same path.
+fn toggle_flythrough(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    seed: Res<TerrainSeed>,
+    mut flythrough: ResMut<Flythrough>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    flythrough.active = !flythrough.active;
+    if flythrough.active {
+        let mut rng = StdRng::seed_from_u64(seed.0 as u64);
+        flythrough.elapsed = 0.0;
+        for phase in &mut flythrough.phases {
+            *phase = rng.random_range(0.0..std::f32::consts::TAU);
+        }
+    }
+    info!("flythrough = {}", flythrough.active);
+}
+
+fn stop_flythrough(mut flythrough: ResMut<Flythrough>) {
+    flythrough.active = false;
+}
+
+/// Walk forward at chase speed along the wandering heading, facing the way
+/// we're going. Height is left to `follow_terrain_height`.
+fn fly_player(
+    time: Res<Time>,
+    mut flythrough: ResMut<Flythrough>,
+    mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+) {
+    let Ok((mut transform, mut look)) = player.single_mut() else {
+        return;
+    };
+    flythrough.elapsed += time.delta_secs();
+    let t = flythrough.elapsed;
+
+    let heading: f32 = FLY_OCTAVES
+        .iter()
+        .zip(flythrough.phases)
+        .map(|((freq, amp), phase)| (t * freq + phase).sin() * amp)
+        .sum();
+    look.yaw = heading;
+    look.pitch = 0.0;
+    transform.rotation = Quat::from_rotation_y(look.yaw);
+
+    let forward = transform.forward();
+    let forward_xz = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+    transform.translation += forward_xz * MOVE_SPEED * time.delta_secs();
+}
+
+/// G toggles the terrain gizmo overlay.
+fn toggle_terrain_gizmos(keyboard: Res<ButtonInput<KeyCode>>, mut enabled: ResMut<TerrainGizmos>) {
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        enabled.0 = !enabled.0;
+    }
+}
+
+/// Draw the chunk grid around the player, the quadrant origin and boundaries,
+/// the visible axis and any active stale chunk.
+fn draw_terrain_gizmos(
+    mut gizmos: Gizmos,
+    sampler: Res<NoiseSampler>,
+    stale: Res<StaleChunk>,
+    config: Res<TerrainConfig>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let size = config.chunk_size;
+    // Draw just below eye level so the overlay sits over the nearby ground.
+    let y = transform.translation.y - 1.0;
+    let at = |x: f32, z: f32| Vec3::new(x, y, z);
+
+    // Chunk grid.
+    let player_cx = (transform.translation.x / size).floor() as i32;
+    let player_cz = (transform.translation.z / size).floor() as i32;
+    let min_x = (player_cx - GIZMO_GRID_CHUNKS) as f32 * size;
+    let max_x = (player_cx + GIZMO_GRID_CHUNKS + 1) as f32 * size;
+    let min_z = (player_cz - GIZMO_GRID_CHUNKS) as f32 * size;
+    let max_z = (player_cz + GIZMO_GRID_CHUNKS + 1) as f32 * size;
+    let grid_colour = Color::srgba(1.0, 1.0, 1.0, 0.2);
+    for i in -GIZMO_GRID_CHUNKS..=GIZMO_GRID_CHUNKS + 1 {
+        let x = (player_cx + i) as f32 * size;
+        let z = (player_cz + i) as f32 * size;
+        gizmos.line(at(x, min_z), at(x, max_z), grid_colour);
+        gizmos.line(at(min_x, z), at(max_x, z), grid_colour);
+    }
+
+    // Quadrant boundaries through the origin.
+    let origin = sampler.quadrant_origin;
+    let reach = config.render_radius as f32 * size;
+    let boundary_colour = Color::srgb(1.0, 1.0, 0.0);
+    gizmos.line(
+        at(origin.x - reach, origin.y),
+        at(origin.x + reach, origin.y),
+        boundary_colour,
+    );
+    gizmos.line(
+        at(origin.x, origin.y - reach),
+        at(origin.x, origin.y + reach),
+        boundary_colour,
+    );
+    gizmos.sphere(
+        Isometry3d::from_translation(at(origin.x, origin.y)),
+        0.5,
+        boundary_colour,
+    );
+
+    // Visible axis arrow from the origin.
+    let visible = sampler.visible_axis.dir_2d() * size * 2.0;
+    gizmos.arrow(
+        at(origin.x, origin.y),
+        at(origin.x + visible.x, origin.y + visible.y),
+        Color::srgb(0.0, 1.0, 1.0),
+    );
+
+    // Stale chunk outline.
+    if let Some(stale) = stale.0.as_ref() {
+        let x0 = stale.grid_pos.0 as f32 * size;
+        let z0 = stale.grid_pos.1 as f32 * size;
+        let corners = [
+            at(x0, z0),
+            at(x0 + size, z0),
+            at(x0 + size, z0 + size),
+            at(x0, z0 + size),
+        ];
+        gizmos.linestrip(
+            corners.into_iter().chain([corners[0]]),
+            Color::srgb(1.0, 0.0, 1.0),
+        );
+    }
+}
+
+fn toggle_minimap(keyboard: Res<ButtonInput<KeyCode>>, mut minimap: ResMut<Minimap>) {
+    if keyboard.just_pressed(KeyCode::KeyJ) {
+        minimap.0 = !minimap.0;
+    }
+}
+
+/// Build or tear down the minimap to match the toggle. It goes with the chase,
+/// so this also brings it back on re-entering.
+fn sync_minimap(
+    mut commands: Commands,
+    minimap: Res<Minimap>,
+    root: Query<Entity, With<MinimapRoot>>,
+) {
+    match (minimap.0, root.single()) {
+        (true, Err(_)) => spawn_minimap(&mut commands),
+        (false, Ok(root)) => commands.entity(root).despawn(),
+        _ => {}
+    }
+}
+
+fn spawn_minimap(commands: &mut Commands) {
+    let cells = 2 * MINIMAP_CHUNKS + 1;
+    let side = Val::Px(cells as f32 * MINIMAP_CELL_PX);
+    commands
+        .spawn((
+            MinimapRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(16.0),
+                top: Val::Px(16.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+            DespawnOnExit(Sections::Chase),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                MinimapAxisLabel,
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.0, 1.0, 1.0)),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: side,
+                        height: side,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                ))
+                .with_children(|grid| {
+                    for dz in -MINIMAP_CHUNKS..=MINIMAP_CHUNKS {
+                        for dx in -MINIMAP_CHUNKS..=MINIMAP_CHUNKS {
+                            // North (-z) is up.
+                            grid.spawn((
+                                MinimapCell { dx, dz },
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px((dx + MINIMAP_CHUNKS) as f32 * MINIMAP_CELL_PX),
+                                    top: Val::Px((dz + MINIMAP_CHUNKS) as f32 * MINIMAP_CELL_PX),
+                                    width: Val::Px(MINIMAP_CELL_PX - 1.0),
+                                    height: Val::Px(MINIMAP_CELL_PX - 1.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::NONE),
+                            ));
+                        }
+                    }
+                });
+        });
+}
+
+/// Colour each cell by whether its chunk is spawned and which quadrant it
+/// falls in, with the stale chunk and the player's own chunk on top.
+fn draw_minimap(
+    spawned: Res<SpawnedChunks>,
+    stale: Res<StaleChunk>,
+    sampler: Res<NoiseSampler>,
+    config: Res<TerrainConfig>,
+    player: Query<&Transform, With<Player>>,
+    mut cells: Query<(&MinimapCell, &mut BackgroundColor)>,
+    mut label: Query<&mut Text, With<MinimapAxisLabel>>,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let size = config.chunk_size;
+    let player_cx = (transform.translation.x / size).floor() as i32;
+    let player_cz = (transform.translation.z / size).floor() as i32;
+    let stale_pos = stale.0.as_ref().map(|s| s.grid_pos);
+    let origin = sampler.quadrant_origin;
+
+    for (cell, mut background) in &mut cells {
+        let grid_pos = (player_cx + cell.dx, player_cz + cell.dz);
+        let colour = if cell.dx == 0 && cell.dz == 0 {
+            Color::WHITE
+        } else if stale_pos == Some(grid_pos) {
+            Color::srgb(1.0, 0.0, 1.0)
+        } else if spawned.0.contains(&grid_pos) {
+            let centre_x = (grid_pos.0 as f32 + 0.5) * size;
+            let centre_z = (grid_pos.1 as f32 + 0.5) * size;
+            let quadrant = match (centre_x >= origin.x, centre_z >= origin.y) {
+                (true, true) => 0,
+                (false, true) => 1,
+                (false, false) => 2,
+                (true, false) => 3,
+            };
+            MINIMAP_QUADRANT_COLOURS[quadrant]
+        } else {
+            Color::NONE
+        };
+        if background.0 != colour {
+            background.0 = colour;
+        }
+    }
+
+    if let Ok(mut text) = label.single_mut() {
+        let axis = format!("Visible: {:?}", sampler.visible_axis);
+        if text.0 != axis {
+            text.0 = axis;
+        }
+    }
+}
+
+/// Systems read these with `single()` and quietly do nothing on error, so a
+/// missed despawn shows up as a feature going dead. Say so once per type.
+fn warn_duplicates<T: Component>(query: Query<(), With<T>>, mut warned: Local<bool>) {
+    let count = query.iter().count();
+    if count > 1 && !*warned {
+        *warned = true;
+        warn!(
+            "{count} entities with {}; expected at most one",
+            std::any::type_name::<T>()
+        );
+    }
+}
@@ -1,10 +1,7 @@
 // DeepDream style post-processing effect with yellow tint, procedural eyes, swirl tendrils,
-// and chromatic aberration.
+// chromatic aberration, vignette and film grain.
 use bevy::{
-    core_pipeline::{
-        core_3d::graph::Node3d,
-        fullscreen_material::{FullscreenMaterial, FullscreenMaterialPlugin},
-    },
+    core_pipeline::{core_3d::graph::Node3d, fullscreen_material::FullscreenMaterial},
     prelude::*,
     render::{
         extract_component::ExtractComponent,
@@ -14,28 +11,75 @@ use bevy::{
     shader::ShaderRef,
 };
 
+use crate::sections::Sections;
+use crate::settings::AccessibilitySettings;
+use crate::terrain::TerrainSeed;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::core_pipeline::fullscreen_material::FullscreenMaterialPlugin;
+
 pub struct DreamPlugin;
 
 impl Plugin for DreamPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(FullscreenMaterialPlugin::<DreamSettings>::default())
-            .add_systems(Update, update_dream_time);
+        app.init_resource::<DreamTimeFrozen>()
+            .init_resource::<DevOverlay>()
+            .register_type::<DreamSettings>()
+            .add_systems(
+                Update,
+                (
+                    update_dream_time.run_if(|frozen: Res<DreamTimeFrozen>| !frozen.0),
+                    apply_reduced_motion,
+                    apply_section_grade.run_if(state_changed::<Sections>),
+                ),
+            );
+
+        // Fullscreen post-process materials fail on some WebGL2 targets, so
+        // the web build drives the escalation with a tinted UI overlay instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_plugins(FullscreenMaterialPlugin::<DreamSettings>::default());
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(Startup, spawn_dream_overlay)
+            .add_systems(Update, update_dream_overlay);
 
-        #[cfg(debug_assertions)]
+        // Available in every build profile, so the ramp can be shown off from
+        // a release build.
         app.add_systems(Startup, spawn_intensity_display)
-            .add_systems(Update, adjust_intensity);
+            .add_systems(
+                Update,
+                (
+                    toggle_dev_overlay,
+                    (adjust_intensity, print_seed).run_if(|overlay: Res<DevOverlay>| overlay.0),
+                    update_intensity_display,
+                )
+                    .chain(),
+            );
     }
 }
 
 /// Controls the DeepDream post-processing effect. Add to a camera entity.
-#[derive(Component, ExtractComponent, Clone, Copy, ShaderType, Default)]
+#[derive(Component, ExtractComponent, Clone, Copy, ShaderType, Default, Reflect)]
+#[reflect(Component)]
 pub struct DreamSettings {
     /// Effect strength from 0.0 (off) to 1.0 (full).
     pub intensity: f32,
     /// Elapsed time in seconds, drives subtle animation.
     pub time: f32,
+    /// Vignette darkness at full intensity.
+    pub vignette: f32,
+    /// Film grain strength at full intensity.
+    pub grain: f32,
+    /// Multiplier on the swirl tendrils.
+    pub swirl: f32,
+    /// How far the section grade pulls the image toward `tint`, independent of
+    /// `intensity`.
+    pub tint_strength: f32,
+    // Pads the uniform to a 16-byte multiple for WebGL2.
     pub _align: f32,
     pub _align2: f32,
+    /// Per-channel multiplier for the section's base colour grade.
+    pub tint: Vec3,
+    pub _align3: f32,
 }
 
 impl FullscreenMaterial for DreamSettings {
@@ -52,24 +96,115 @@ impl FullscreenMaterial for DreamSettings {
     }
 }
 
+/// Holds the dream animation still, for screenshots and visual comparisons.
+/// Photo mode needs no help: pausing virtual time already stops it.
+#[derive(Resource, Default)]
+pub struct DreamTimeFrozen(pub bool);
+
 fn update_dream_time(mut query: Query<&mut DreamSettings>, time: Res<Time>) {
     for mut settings in &mut query {
         settings.time = time.elapsed_secs();
     }
 }
 
-#[cfg(debug_assertions)]
+/// Swirl strength kept when reduced motion is on.
+const REDUCED_MOTION_SWIRL: f32 = 0.2;
+
+fn apply_reduced_motion(
+    mut query: Query<&mut DreamSettings>,
+    accessibility: Res<AccessibilitySettings>,
+) {
+    let swirl = if accessibility.reduced_motion {
+        REDUCED_MOTION_SWIRL
+    } else {
+        1.0
+    };
+    for mut settings in &mut query {
+        settings.swirl = swirl;
+    }
+}
+
+/// Base colour grade for each section, as a per-channel tint and how strongly
+/// it applies. The chase keeps the raw image so its yellow ramp reads cleanly.
+fn section_grade(section: Sections) -> (Vec3, f32) {
+    match section {
+        Sections::Menu | Sections::Chase => (Vec3::ONE, 0.0),
+        Sections::Underworld => (Vec3::new(0.7, 0.85, 1.2), 0.6),
+        Sections::Stairs => (Vec3::new(0.85, 0.9, 1.1), 0.3),
+        Sections::Awaken => (Vec3::new(1.15, 1.0, 0.8), 0.4),
+    }
+}
+
+fn apply_section_grade(section: Res<State<Sections>>, mut query: Query<&mut DreamSettings>) {
+    let (tint, strength) = section_grade(**section);
+    for mut settings in &mut query {
+        settings.tint = tint;
+        settings.tint_strength = strength;
+    }
+}
+
+/// Max overlay alpha at full intensity when the shader path is unavailable.
+#[cfg(target_arch = "wasm32")]
+const OVERLAY_MAX_ALPHA: f32 = 0.45;
+
+/// Full-screen tint standing in for the dream shader.
+#[cfg(target_arch = "wasm32")]
+#[derive(Component)]
+struct DreamOverlay;
+
+#[cfg(target_arch = "wasm32")]
+fn spawn_dream_overlay(mut commands: Commands) {
+    commands.spawn((
+        DreamOverlay,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(50),
+        Pickable::IGNORE,
+    ));
+}
+
+/// Fade the overlay from clear to a golden haze, darkening toward red-brown
+/// near full intensity to echo the shader's tint and vignette.
+#[cfg(target_arch = "wasm32")]
+fn update_dream_overlay(
+    dream_query: Query<&DreamSettings>,
+    mut overlay: Query<&mut BackgroundColor, With<DreamOverlay>>,
+) {
+    let Ok(mut bg) = overlay.single_mut() else {
+        return;
+    };
+    let intensity = dream_query
+        .single()
+        .map(|settings| settings.intensity)
+        .unwrap_or(0.0);
+
+    let golden = Vec3::new(1.0, 0.85, 0.3);
+    let deep = Vec3::new(0.5, 0.2, 0.05);
+    let tint = golden.lerp(deep, intensity * intensity);
+    bg.0 = Color::srgba(tint.x, tint.y, tint.z, intensity * OVERLAY_MAX_ALPHA);
+}
+
 const INTENSITY_STEP: f32 = 0.05;
+const DEV_OVERLAY_KEY: KeyCode = KeyCode::F3;
+/// Logs the terrain seed so a striking landscape can be noted down.
+const PRINT_SEED_KEY: KeyCode = KeyCode::F4;
+
+/// Live intensity and seed readout with arrow-key control over the intensity,
+/// toggled with F3 in any build.
+#[derive(Resource, Default)]
+pub struct DevOverlay(pub bool);
 
-#[cfg(debug_assertions)]
 #[derive(Component)]
 struct IntensityDisplay;
 
-#[cfg(debug_assertions)]
 fn spawn_intensity_display(mut commands: Commands) {
     commands.spawn((
         IntensityDisplay,
-        //Text::new("Intensity: 0.00"),
         Text::new(""),
         TextFont {
             font_size: 20.0,
@@ -78,37 +213,60 @@ fn spawn_intensity_display(mut commands: Commands) {
         TextColor(Color::WHITE),
         Node {
             position_type: PositionType::Absolute,
-            top: Val::Px(10.0),
+            // Clear of the run timer in the top corner.
+            bottom: Val::Px(10.0),
             left: Val::Px(10.0),
             ..default()
         },
+        Pickable::IGNORE,
     ));
 }
 
-#[cfg(debug_assertions)]
+fn toggle_dev_overlay(keyboard: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<DevOverlay>) {
+    if keyboard.just_pressed(DEV_OVERLAY_KEY) {
+        overlay.0 = !overlay.0;
+    }
+}
+
 fn adjust_intensity(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut dream_query: Query<&mut DreamSettings>,
-    mut text_query: Query<&mut Text, With<IntensityDisplay>>,
 ) {
     let Ok(mut settings) = dream_query.single_mut() else {
         return;
     };
-
-    let mut changed = false;
     if keyboard.just_pressed(KeyCode::ArrowUp) {
         settings.intensity = (settings.intensity + INTENSITY_STEP).min(1.0);
-        changed = true;
     }
     if keyboard.just_pressed(KeyCode::ArrowDown) {
         settings.intensity = (settings.intensity - INTENSITY_STEP).max(0.0);
-        changed = true;
     }
+}
+
+fn print_seed(keyboard: Res<ButtonInput<KeyCode>>, seed: Res<TerrainSeed>) {
+    if keyboard.just_pressed(PRINT_SEED_KEY) {
+        info!("Terrain seed: {}", seed.0);
+    }
+}
 
-    if changed {
-        if let Ok(mut text) = text_query.single_mut() {
-            //**text = format!("Intensity: {:.2}", settings.intensity);
-            **text = String::new();
-        }
+/// Follows the ramp every frame while the overlay is on, blank otherwise.
+fn update_intensity_display(
+    overlay: Res<DevOverlay>,
+    seed: Res<TerrainSeed>,
+    dream_query: Query<&DreamSettings>,
+    mut text_query: Query<&mut Text, With<IntensityDisplay>>,
+) {
+    let Ok(mut text) = text_query.single_mut() else {
+        return;
+    };
+    let wanted = match dream_query.single() {
+        Ok(settings) if overlay.0 => format!(
+            "Intensity: {:.2}\nSeed: {} ({PRINT_SEED_KEY:?} to log)",
+            settings.intensity, seed.0
+        ),
+        _ => String::new(),
+    };
+    if text.0 != wanted {
+        text.0 = wanted;
     }
 }
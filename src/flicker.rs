@@ -0,0 +1,62 @@
+// Flame-like flicker for point lights in the dark sections.
+
+use bevy::prelude::*;
+
+pub struct FlickerPlugin;
+
+impl Plugin for FlickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, torch_flicker);
+    }
+}
+
+/// Layered sine octaves as (frequency in Hz, weight). The frequencies are
+/// mutually irrational so the pattern never visibly repeats.
+const FLICKER_OCTAVES: [(f32, f32); 4] = [(0.7, 0.4), (2.3, 0.3), (5.9, 0.2), (13.1, 0.1)];
+
+/// Colour the flame drifts toward as it gutters.
+const EMBER: Color = Color::linear_rgb(1.0, 0.35, 0.1);
+
+/// Modulates a `PointLight`'s intensity and colour around its spawned values.
+#[derive(Component)]
+pub struct TorchFlicker {
+    base_intensity: f32,
+    base_color: Color,
+    /// Fraction of the base intensity the flicker may dip by.
+    depth: f32,
+    /// Phase offset so neighbouring lights don't flicker in lockstep.
+    seed: f32,
+}
+
+impl TorchFlicker {
+    pub fn new(light: &PointLight, depth: f32, seed: f32) -> Self {
+        Self {
+            base_intensity: light.intensity,
+            base_color: light.color,
+            depth,
+            seed,
+        }
+    }
+}
+
+/// Sum of weighted sine octaves, remapped to 0..=1.
+fn flicker_noise(t: f32, seed: f32) -> f32 {
+    let sum: f32 = FLICKER_OCTAVES
+        .iter()
+        .enumerate()
+        .map(|(i, (freq, weight))| {
+            let phase = seed * (i as f32 + 1.0) * 1.618;
+            (t * freq * std::f32::consts::TAU + phase).sin() * weight
+        })
+        .sum();
+    sum * 0.5 + 0.5
+}
+
+fn torch_flicker(time: Res<Time>, mut lights: Query<(&mut PointLight, &TorchFlicker)>) {
+    let t = time.elapsed_secs();
+    for (mut light, flicker) in &mut lights {
+        let dip = (1.0 - flicker_noise(t, flicker.seed)) * flicker.depth;
+        light.intensity = flicker.base_intensity * (1.0 - dip);
+        light.color = flicker.base_color.mix(&EMBER, dip * 0.5);
+    }
+}
@@ -0,0 +1,61 @@
+// Frame limit: VSync through the window's present mode, or a fixed cap held by
+// sleeping off what's left of each frame's budget. Native only; the browser
+// paces the web build to the display.
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow};
+
+use crate::fullscreen::save_display;
+use crate::settings::{DisplaySettings, FrameLimit};
+
+pub struct FrameLimitPlugin;
+
+impl Plugin for FrameLimitPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_present_mode.run_if(resource_changed::<DisplaySettings>),
+        )
+        .add_systems(Last, limit_frame_rate);
+    }
+}
+
+fn apply_present_mode(
+    display: Res<DisplaySettings>,
+    mut window: Query<&mut Window, With<PrimaryWindow>>,
+    mut applied: Local<Option<FrameLimit>>,
+) {
+    let Ok(mut window) = window.single_mut() else {
+        return;
+    };
+    let present_mode = match display.frame_limit {
+        FrameLimit::VSync => PresentMode::AutoVsync,
+        _ => PresentMode::AutoNoVsync,
+    };
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+    // The first run only applies what was loaded; save once it's changed.
+    if applied.is_some_and(|limit| limit != display.frame_limit) {
+        save_display(&display);
+    }
+    *applied = Some(display.frame_limit);
+}
+
+/// Sleep until the frame has taken its share of a second. Measured from when
+/// the last frame's sleep ended, so time spent rendering counts toward it.
+fn limit_frame_rate(display: Res<DisplaySettings>, mut frame_start: Local<Option<Instant>>) {
+    let now = Instant::now();
+    let Some(fps) = display.frame_limit.fps() else {
+        *frame_start = Some(now);
+        return;
+    };
+    let budget = Duration::from_secs_f32(1.0 / fps);
+    let elapsed = frame_start.map_or(budget, |start| now - start);
+    if let Some(remaining) = budget.checked_sub(elapsed) {
+        std::thread::sleep(remaining);
+    }
+    *frame_start = Some(Instant::now());
+}
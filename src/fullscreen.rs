@@ -0,0 +1,106 @@
+// Windowed/fullscreen toggle, remembered between launches on native builds
+// along with the frame limit.
+
+use bevy::prelude::*;
+use bevy::window::{CursorOptions, MonitorSelection, PrimaryWindow, WindowMode};
+
+use crate::settings::DisplaySettings;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::settings::FrameLimit;
+
+pub struct FullscreenPlugin;
+
+impl Plugin for FullscreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_fullscreen).add_systems(
+            Update,
+            (
+                toggle_fullscreen,
+                apply_fullscreen.run_if(resource_changed::<DisplaySettings>),
+            )
+                .chain(),
+        );
+    }
+}
+
+pub(crate) const FULLSCREEN_KEY: KeyCode = KeyCode::F11;
+
+/// Saved next to the executable, like screenshots.
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "settings.cfg";
+
+#[cfg(not(target_arch = "wasm32"))]
+fn settings_path() -> std::path::PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(std::path::PathBuf::from))
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join(SETTINGS_FILE)
+}
+
+/// Restore the last saved choices. A missing or unreadable file keeps the
+/// default windowed mode, and an unknown frame limit the default one.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_fullscreen(mut display: ResMut<DisplaySettings>) {
+    let Ok(contents) = std::fs::read_to_string(settings_path()) else {
+        return;
+    };
+    for line in contents.lines() {
+        match line.split_once('=') {
+            Some(("fullscreen", value)) => display.fullscreen = value.trim() == "true",
+            Some(("frame_limit", value)) => {
+                if let Some(limit) = FrameLimit::from_key(value.trim()) {
+                    display.frame_limit = limit;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The browser owns fullscreen on the web and there is nowhere to save to.
+#[cfg(target_arch = "wasm32")]
+fn load_fullscreen() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn save_display(display: &DisplaySettings) {
+    let contents = format!(
+        "fullscreen={}\nframe_limit={}\n",
+        display.fullscreen,
+        display.frame_limit.key()
+    );
+    if let Err(error) = std::fs::write(settings_path(), contents) {
+        warn!("Couldn't save settings: {error}");
+    }
+}
+
+fn toggle_fullscreen(keyboard: Res<ButtonInput<KeyCode>>, mut display: ResMut<DisplaySettings>) {
+    if keyboard.just_pressed(FULLSCREEN_KEY) {
+        display.fullscreen = !display.fullscreen;
+    }
+}
+
+fn apply_fullscreen(
+    display: Res<DisplaySettings>,
+    mut window: Query<(&mut Window, &mut CursorOptions), With<PrimaryWindow>>,
+) {
+    let Ok((mut window, mut cursor)) = window.single_mut() else {
+        return;
+    };
+    let mode = if display.fullscreen {
+        WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+    } else {
+        WindowMode::Windowed
+    };
+    if window.mode == mode {
+        return;
+    }
+    window.mode = mode;
+
+    // Some platforms drop the cursor grab when the window is recreated at the
+    // new size, so mark it changed to have it applied again.
+    cursor.set_changed();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    save_display(&display);
+}
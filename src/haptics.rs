@@ -0,0 +1,100 @@
+// Gamepad rumble for the story's big moments. Sections ask for a one-off pulse
+// or hold a rumble for as long as a condition lasts; this sends it to every
+// connected gamepad unless haptics are turned off.
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::settings::AccessibilitySettings;
+
+pub struct HapticsPlugin;
+
+impl Plugin for HapticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Haptics>()
+            .add_systems(PostUpdate, send_rumble);
+    }
+}
+
+/// Seconds between refreshes of a held rumble. Each refresh lasts exactly
+/// this long, so they never overlap and stack.
+const HOLD_REFRESH: f32 = 0.1;
+
+/// Motor strengths, 0 to 1, and how long a pulse lasts.
+#[derive(Clone, Copy, Debug)]
+pub struct HapticCue {
+    pub strong: f32,
+    pub weak: f32,
+    pub duration: f32,
+}
+
+impl HapticCue {
+    pub fn scaled(self, amount: f32) -> HapticCue {
+        HapticCue {
+            strong: self.strong * amount,
+            weak: self.weak * amount,
+            ..self
+        }
+    }
+}
+
+/// Rumble requested this frame. Pulses are sent once; the held rumble must be
+/// asked for every frame it should continue, and the strongest ask wins.
+#[derive(Resource, Default)]
+pub struct Haptics {
+    pulses: Vec<HapticCue>,
+    held: Option<HapticCue>,
+}
+
+impl Haptics {
+    pub fn pulse(&mut self, cue: HapticCue) {
+        self.pulses.push(cue);
+    }
+
+    /// Keep rumbling at `cue`'s strength this frame. Its duration is unused.
+    pub fn hold(&mut self, cue: HapticCue) {
+        let stronger = self
+            .held
+            .is_none_or(|held| cue.strong + cue.weak > held.strong + held.weak);
+        if stronger {
+            self.held = Some(cue);
+        }
+    }
+}
+
+fn send_rumble(
+    time: Res<Time<Real>>,
+    accessibility: Res<AccessibilitySettings>,
+    mut haptics: ResMut<Haptics>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut rumble: MessageWriter<GamepadRumbleRequest>,
+    mut since_refresh: Local<f32>,
+) {
+    let pulses = std::mem::take(&mut haptics.pulses);
+    let held = haptics.held.take();
+    *since_refresh += time.delta_secs();
+    if accessibility.no_haptics || gamepads.is_empty() {
+        return;
+    }
+
+    let held = held.filter(|_| *since_refresh >= HOLD_REFRESH).map(|cue| {
+        *since_refresh = 0.0;
+        HapticCue {
+            duration: HOLD_REFRESH,
+            ..cue
+        }
+    });
+    for cue in pulses.into_iter().chain(held) {
+        for gamepad in &gamepads {
+            rumble.write(GamepadRumbleRequest::Add {
+                gamepad,
+                duration: Duration::from_secs_f32(cue.duration),
+                intensity: GamepadRumbleIntensity {
+                    strong_motor: cue.strong.clamp(0.0, 1.0),
+                    weak_motor: cue.weak.clamp(0.0, 1.0),
+                },
+            });
+        }
+    }
+}
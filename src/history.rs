@@ -0,0 +1,163 @@
+// Run history: each finished run's ending, time and plot flags, kept in a few
+// save slots and shown on the menu so players can aim for endings they haven't
+// seen. Saved next to the executable on native builds, in memory on the web.
+
+use bevy::prelude::*;
+
+use crate::awaken::Ending;
+use crate::sections::{PlotFlags, Sections};
+use crate::speedrun::RunTimer;
+
+pub struct HistoryPlugin;
+
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunHistory>()
+            .add_systems(Startup, load_history)
+            .add_systems(OnExit(Sections::Awaken), record_run);
+    }
+}
+
+pub const SLOT_COUNT: usize = 3;
+/// Runs kept per slot. The oldest is dropped past this.
+const HISTORY_LEN: usize = 10;
+
+#[cfg(not(target_arch = "wasm32"))]
+const HISTORY_FILE: &str = "history.cfg";
+
+/// One finished run, as shown in the menu's history.
+#[derive(Clone, Copy, Debug)]
+pub struct RunRecord {
+    pub ending: Ending,
+    pub time: f32,
+    pub looked_behind: bool,
+    pub chevron_count: u32,
+    pub peak_dream_intensity: f32,
+    pub total_rotations: u32,
+}
+
+impl RunRecord {
+    /// `<ending> <time> <looked_behind> <chevrons> <dream> <rotations>`
+    fn to_line(self) -> String {
+        format!(
+            "{:?} {} {} {} {} {}",
+            self.ending,
+            self.time,
+            self.looked_behind,
+            self.chevron_count,
+            self.peak_dream_intensity,
+            self.total_rotations
+        )
+    }
+
+    fn from_fields<'a>(mut fields: impl Iterator<Item = &'a str>) -> Option<RunRecord> {
+        let ending = fields.next()?;
+        Some(RunRecord {
+            ending: Ending::ALL
+                .into_iter()
+                .find(|e| format!("{e:?}") == ending)?,
+            time: fields.next()?.parse().ok()?,
+            looked_behind: fields.next()?.parse().ok()?,
+            chevron_count: fields.next()?.parse().ok()?,
+            peak_dream_intensity: fields.next()?.parse().ok()?,
+            total_rotations: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+/// The selected slot and every slot's runs, newest last.
+#[derive(Resource, Default)]
+pub struct RunHistory {
+    pub slot: usize,
+    slots: [Vec<RunRecord>; SLOT_COUNT],
+}
+
+impl RunHistory {
+    /// Runs in the selected slot, newest last.
+    pub fn runs(&self) -> &[RunRecord] {
+        &self.slots[self.slot]
+    }
+
+    pub fn next_slot(&mut self) {
+        self.slot = (self.slot + 1) % SLOT_COUNT;
+        #[cfg(not(target_arch = "wasm32"))]
+        self.save();
+    }
+
+    fn push(&mut self, record: RunRecord) {
+        let runs = &mut self.slots[self.slot];
+        runs.push(record);
+        if runs.len() > HISTORY_LEN {
+            runs.remove(0);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        self.save();
+    }
+
+    /// `slot=<n>` then one `<slot> <record>` line per run.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save(&self) {
+        let mut contents = format!("slot={}\n", self.slot);
+        for (slot, runs) in self.slots.iter().enumerate() {
+            for run in runs {
+                contents.push_str(&format!("{slot} {}\n", run.to_line()));
+            }
+        }
+        if let Err(error) = std::fs::write(history_path(), contents) {
+            warn!("Couldn't save run history: {error}");
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn history_path() -> std::path::PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(std::path::PathBuf::from))
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join(HISTORY_FILE)
+}
+
+/// Lines that don't parse are skipped rather than losing the whole history.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_history(mut history: ResMut<RunHistory>) {
+    let Ok(contents) = std::fs::read_to_string(history_path()) else {
+        return;
+    };
+    for line in contents.lines() {
+        if let Some(slot) = line.strip_prefix("slot=") {
+            history.slot = slot.trim().parse().unwrap_or(0).min(SLOT_COUNT - 1);
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let Some(slot) = fields
+            .next()
+            .and_then(|slot| slot.parse::<usize>().ok())
+            .filter(|slot| *slot < SLOT_COUNT)
+        else {
+            continue;
+        };
+        if let Some(record) = RunRecord::from_fields(fields) {
+            history.slots[slot].push(record);
+        }
+    }
+    for runs in &mut history.slots {
+        let excess = runs.len().saturating_sub(HISTORY_LEN);
+        runs.drain(..excess);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_history() {}
+
+/// Leaving the room, by waiting out the stats or quitting, finishes the run.
+fn record_run(flags: Res<PlotFlags>, timer: Res<RunTimer>, mut history: ResMut<RunHistory>) {
+    history.push(RunRecord {
+        ending: Ending::from_flags(&flags),
+        time: timer.total,
+        looked_behind: flags.player_looked_behind,
+        chevron_count: flags.chevron_count,
+        peak_dream_intensity: flags.peak_dream_intensity,
+        total_rotations: flags.total_rotations,
+    });
+}
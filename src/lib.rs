@@ -0,0 +1,133 @@
+// Game setup. A library so benches can reach the terrain code; the binary
+// in main.rs just calls `run`.
+#![allow(clippy::collapsible_if)]
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+
+mod awaken;
+mod chase;
+mod chevron;
+mod compass;
+#[cfg(debug_assertions)]
+mod debug;
+mod dream;
+mod flicker;
+#[cfg(not(target_arch = "wasm32"))]
+mod frame_limit;
+mod fullscreen;
+mod haptics;
+mod history;
+mod menu;
+mod npc;
+mod particles;
+mod photo;
+mod player;
+mod prompt;
+mod quality;
+mod replay;
+mod screenshot;
+mod sections;
+mod settings;
+#[cfg(target_arch = "wasm32")]
+mod sky;
+mod speedrun;
+mod stairs;
+pub mod terrain;
+mod third_person;
+mod trail;
+mod transition;
+mod underworld;
+
+use awaken::AwakenPlugin;
+use bevy::prelude::*;
+use chase::ChasePlugin;
+use compass::CompassPlugin;
+#[cfg(debug_assertions)]
+use debug::DebugPlugin;
+use dream::DreamPlugin;
+use flicker::FlickerPlugin;
+#[cfg(not(target_arch = "wasm32"))]
+use frame_limit::FrameLimitPlugin;
+use fullscreen::FullscreenPlugin;
+use haptics::HapticsPlugin;
+use history::HistoryPlugin;
+use menu::MenuPlugin;
+use npc::NpcPlugin;
+use particles::ParticlesPlugin;
+use photo::PhotoPlugin;
+use player::PlayerPlugin;
+use prompt::PromptPlugin;
+use quality::QualityPlugin;
+use replay::ReplayPlugin;
+use screenshot::ScreenshotPlugin;
+use sections::{PlotFlags, Sections};
+use settings::{
+    AccessibilitySettings, AutoCamera, CameraSettings, Difficulty, DisplaySettings, InputSettings,
+    QualityPreset, RenderDistance, ThirdPerson,
+};
+#[cfg(target_arch = "wasm32")]
+use sky::SkyPlugin;
+use speedrun::SpeedrunPlugin;
+use stairs::StairsPlugin;
+use terrain::TerrainPlugin;
+use third_person::ThirdPersonPlugin;
+use trail::TrailPlugin;
+use transition::TransitionPlugin;
+use underworld::UnderworldPlugin;
+
+/// Build the app with every section's plugins and run it.
+pub fn run() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins)
+        .init_state::<Sections>()
+        .init_resource::<PlotFlags>()
+        .register_type::<PlotFlags>()
+        .init_resource::<Difficulty>()
+        .init_resource::<InputSettings>()
+        .init_resource::<AccessibilitySettings>()
+        .init_resource::<AutoCamera>()
+        .init_resource::<ThirdPerson>()
+        .init_resource::<DisplaySettings>()
+        .init_resource::<CameraSettings>()
+        .init_resource::<RenderDistance>()
+        .init_resource::<QualityPreset>()
+        .add_plugins((
+            MenuPlugin,
+            PlayerPlugin,
+            TerrainPlugin,
+            DreamPlugin,
+            NpcPlugin,
+            ChasePlugin,
+            UnderworldPlugin,
+            StairsPlugin,
+            AwakenPlugin,
+            TransitionPlugin,
+            PhotoPlugin,
+            ScreenshotPlugin,
+        ))
+        .add_plugins((
+            CompassPlugin,
+            FlickerPlugin,
+            SpeedrunPlugin,
+            ParticlesPlugin,
+            FullscreenPlugin,
+            QualityPlugin,
+            TrailPlugin,
+            PromptPlugin,
+            HistoryPlugin,
+            ThirdPersonPlugin,
+            HapticsPlugin,
+            ReplayPlugin,
+        ));
+
+    #[cfg(target_arch = "wasm32")]
+    app.add_plugins(SkyPlugin);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    app.add_plugins(FrameLimitPlugin);
+
+    #[cfg(debug_assertions)]
+    app.add_plugins(DebugPlugin);
+
+    app.run();
+}
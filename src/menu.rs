@@ -1,36 +1,207 @@
 // Main menu
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, CursorOptions};
 
+use crate::chase::AUTO_CAMERA_KEY;
+use crate::fullscreen::FULLSCREEN_KEY;
+use crate::history::RunHistory;
+use crate::npc::NpcChevron;
+use crate::photo::{PHOTO_KEY, photo_mode_inactive};
+use crate::screenshot::SCREENSHOT_KEY;
 use crate::sections::Sections;
+use crate::settings::{
+    AccessibilitySettings, CameraSettings, Difficulty, DisplaySettings, InputSettings,
+    QualityPreset,
+};
+use crate::speedrun::{TIMER_KEY, format_time};
+use crate::third_person::THIRD_PERSON_KEY;
+use crate::transition::AmbientFade;
 
 pub struct MenuPlugin;
 
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(Sections::Menu), setup_menu)
+        app.add_systems(OnEnter(Sections::Menu), (reset_for_menu, setup_menu))
             .add_systems(
                 Update,
-                (button_visuals, button_actions, credits_back).run_if(in_state(Sections::Menu)),
+                (
+                    button_visuals,
+                    button_actions,
+                    setting_labels.after(button_actions),
+                    credits_scroll,
+                    overlay_back,
+                )
+                    .run_if(in_state(Sections::Menu)),
+            )
+            .add_systems(
+                Update,
+                quit_to_menu
+                    .run_if(
+                        in_state(Sections::Chase)
+                            .or(in_state(Sections::Underworld))
+                            .or(in_state(Sections::Stairs))
+                            .or(in_state(Sections::Awaken)),
+                    )
+                    .run_if(photo_mode_inactive),
             );
     }
 }
 
+/// Abandons the run from any section.
+pub(crate) const MENU_KEY: KeyCode = KeyCode::KeyM;
+
 const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
 const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
 const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.35, 0.35);
 
+/// Seconds for the credits to scroll from the bottom of the screen to the top.
+const CREDITS_DURATION: f32 = 20.0;
+
 #[derive(Component)]
 enum MenuButton {
     Start,
+    Difficulty,
+    Brightness,
+    Fov,
+    Fullscreen,
+    #[cfg(not(target_arch = "wasm32"))]
+    FrameLimit,
+    Quality,
+    Slot,
+    Accessibility,
+    HeadBob,
+    HighContrastChevron,
+    ReducedMotion,
+    Rumble,
+    SoftFail,
+    NpcCorruption,
+    History,
+    Controls,
     Credits,
     #[cfg(not(target_arch = "wasm32"))]
     Exit,
 }
 
+/// Full-screen panel over the menu, dismissed by its Back button.
+#[derive(Component)]
+struct MenuOverlay;
+
 #[derive(Component)]
 struct CreditsOverlay;
 
+/// Scrolling column of credit lines inside the overlay.
+#[derive(Component)]
+struct CreditsScroll {
+    elapsed: f32,
+    /// Close the credits once they finish scrolling, rather than waiting on
+    /// the Back button.
+    auto_close: bool,
+}
+
+impl Default for CreditsScroll {
+    fn default() -> Self {
+        Self {
+            elapsed: 0.0,
+            auto_close: true,
+        }
+    }
+}
+
+/// The setting a button's text shows. Labels are rewritten from the settings
+/// whenever one changes, from the menu or from a key like F11.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+enum SettingLabel {
+    Difficulty,
+    Brightness,
+    Fov,
+    Fullscreen,
+    #[cfg(not(target_arch = "wasm32"))]
+    FrameLimit,
+    Quality,
+    Slot,
+    HeadBob,
+    HighContrastChevron,
+    ReducedMotion,
+    Rumble,
+    SoftFail,
+    NpcCorruption,
+}
+
+/// Everything the settings buttons show.
+#[derive(SystemParam)]
+struct MenuSettings<'w> {
+    difficulty: Res<'w, Difficulty>,
+    display: Res<'w, DisplaySettings>,
+    camera: Res<'w, CameraSettings>,
+    quality: Res<'w, QualityPreset>,
+    history: Res<'w, RunHistory>,
+    input: Res<'w, InputSettings>,
+    accessibility: Res<'w, AccessibilitySettings>,
+}
+
+impl MenuSettings<'_> {
+    fn is_changed(&self) -> bool {
+        self.difficulty.is_changed()
+            || self.display.is_changed()
+            || self.camera.is_changed()
+            || self.quality.is_changed()
+            || self.history.is_changed()
+            || self.input.is_changed()
+            || self.accessibility.is_changed()
+    }
+
+    fn text(&self, label: SettingLabel) -> String {
+        match label {
+            SettingLabel::Difficulty => format!("Difficulty: {}", self.difficulty.label()),
+            SettingLabel::Brightness => {
+                format!("Brightness: {}%", (self.display.brightness * 100.0).round())
+            }
+            SettingLabel::Fov => format!("Field of view: {}", self.camera.fov.round()),
+            SettingLabel::Fullscreen => format!("Fullscreen: {}", on_off(self.display.fullscreen)),
+            #[cfg(not(target_arch = "wasm32"))]
+            SettingLabel::FrameLimit => {
+                format!("Frame limit: {}", self.display.frame_limit.label())
+            }
+            SettingLabel::Quality => format!("Quality: {}", self.quality.label()),
+            SettingLabel::Slot => format!("Save slot: {}", self.history.slot + 1),
+            SettingLabel::HeadBob => format!("Head-bob: {}", on_off(self.input.head_bob)),
+            SettingLabel::HighContrastChevron => {
+                format!(
+                    "High contrast: {}",
+                    on_off(self.accessibility.high_contrast_chevron)
+                )
+            }
+            SettingLabel::ReducedMotion => {
+                format!(
+                    "Reduced motion: {}",
+                    on_off(self.accessibility.reduced_motion)
+                )
+            }
+            SettingLabel::Rumble => {
+                format!("Rumble: {}", on_off(!self.accessibility.no_haptics))
+            }
+            SettingLabel::SoftFail => {
+                format!(
+                    "Restart when lost: {}",
+                    on_off(!self.accessibility.no_soft_fail)
+                )
+            }
+            SettingLabel::NpcCorruption => {
+                format!(
+                    "NPC corruption: {}",
+                    on_off(!self.accessibility.no_npc_corruption)
+                )
+            }
+        }
+    }
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on { "On" } else { "Off" }
+}
+
 fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Root container.
     commands
@@ -41,7 +212,7 @@ fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                row_gap: Val::Px(24.0),
+                row_gap: Val::Px(12.0),
                 ..default()
             },
             DespawnOnExit(Sections::Menu),
@@ -59,25 +230,88 @@ fn setup_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             ));
 
             // Start button.
-            spawn_button(parent, "Start", MenuButton::Start);
+            spawn_button(parent, "Start", MenuButton::Start, None);
+
+            // Difficulty button, cycles through presets.
+            spawn_setting_button(parent, MenuButton::Difficulty, SettingLabel::Difficulty);
+
+            // Brightness button, cycles through exposure steps.
+            spawn_setting_button(parent, MenuButton::Brightness, SettingLabel::Brightness);
+
+            // Field-of-view button, cycles through presets.
+            spawn_setting_button(parent, MenuButton::Fov, SettingLabel::Fov);
+
+            // Fullscreen button, toggles the window mode.
+            spawn_setting_button(parent, MenuButton::Fullscreen, SettingLabel::Fullscreen);
+
+            // Frame limit button, cycles VSync and fixed caps (native only).
+            #[cfg(not(target_arch = "wasm32"))]
+            spawn_setting_button(parent, MenuButton::FrameLimit, SettingLabel::FrameLimit);
+
+            // Quality button, cycles through presets.
+            spawn_setting_button(parent, MenuButton::Quality, SettingLabel::Quality);
+
+            // Save slot button, cycles through slots.
+            spawn_setting_button(parent, MenuButton::Slot, SettingLabel::Slot);
+
+            // Accessibility button, opens the comfort and readability options.
+            spawn_button(parent, "Accessibility", MenuButton::Accessibility, None);
+
+            // History button, lists the slot's finished runs.
+            spawn_button(parent, "History", MenuButton::History, None);
+
+            // Controls button.
+            spawn_button(parent, "Controls", MenuButton::Controls, None);
 
             // Credits button.
-            spawn_button(parent, "Credits", MenuButton::Credits);
+            spawn_button(parent, "Credits", MenuButton::Credits, None);
 
             // Exit button (native only).
             #[cfg(not(target_arch = "wasm32"))]
-            spawn_button(parent, "Exit", MenuButton::Exit);
+            spawn_button(parent, "Exit", MenuButton::Exit, None);
         });
 }
 
-fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, marker: MenuButton) {
+fn quit_to_menu(keyboard: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<Sections>>) {
+    if keyboard.just_pressed(MENU_KEY) {
+        next_state.set(Sections::Menu);
+    }
+}
+
+/// Each section's OnExit tears down its own entities, but only finishes the
+/// job its usual successor expects. Arriving at the menu from anywhere also
+/// needs the world state put back to how the menu first saw it.
+fn reset_for_menu(
+    mut ambient: ResMut<AmbientFade>,
+    mut clear_color: ResMut<ClearColor>,
+    mut cursor: Query<&mut CursorOptions>,
+    mut chevron: Query<&mut Visibility, With<NpcChevron>>,
+) {
+    ambient.fade_out();
+    clear_color.0 = Color::BLACK;
+    if let Ok(mut cursor) = cursor.single_mut() {
+        cursor.grab_mode = CursorGrabMode::None;
+        cursor.visible = true;
+    }
+    for mut visibility in &mut chevron {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// A menu button. `setting` marks its text to follow that setting.
+fn spawn_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    marker: MenuButton,
+    setting: Option<SettingLabel>,
+) {
     parent
         .spawn((
             marker,
             Button,
             Node {
-                width: Val::Px(200.0),
-                height: Val::Px(50.0),
+                width: Val::Px(260.0),
+                height: Val::Px(40.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 border: UiRect::all(Val::Px(2.0)),
@@ -87,7 +321,7 @@ fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, marker: MenuButt
             BackgroundColor(NORMAL_BUTTON),
         ))
         .with_children(|btn| {
-            btn.spawn((
+            let mut text = btn.spawn((
                 Text::new(label),
                 TextFont {
                     font_size: 24.0,
@@ -95,9 +329,32 @@ fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, marker: MenuButt
                 },
                 TextColor(Color::WHITE),
             ));
+            if let Some(setting) = setting {
+                text.insert(setting);
+            }
         });
 }
 
+/// A button showing a setting. Its text is filled in by `setting_labels`.
+fn spawn_setting_button(
+    parent: &mut ChildSpawnerCommands,
+    marker: MenuButton,
+    setting: SettingLabel,
+) {
+    spawn_button(parent, "", marker, Some(setting));
+}
+
+/// Settings can also change from keys, so follow the settings rather than the
+/// buttons. New labels, on the menu or an overlay, are filled in as they appear.
+fn setting_labels(settings: MenuSettings, mut labels: Query<(&mut Text, Ref<SettingLabel>)>) {
+    let changed = settings.is_changed();
+    for (mut text, label) in &mut labels {
+        if changed || label.is_added() {
+            **text = settings.text(*label);
+        }
+    }
+}
+
 fn button_visuals(
     mut query: Query<
         (&Interaction, &mut BackgroundColor, &mut BorderColor),
@@ -125,6 +382,13 @@ fn button_visuals(
 fn button_actions(
     query: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
     mut next_state: ResMut<NextState<Sections>>,
+    mut difficulty: ResMut<Difficulty>,
+    mut display: ResMut<DisplaySettings>,
+    mut camera: ResMut<CameraSettings>,
+    mut quality: ResMut<QualityPreset>,
+    mut history: ResMut<RunHistory>,
+    mut input: ResMut<InputSettings>,
+    mut accessibility: ResMut<AccessibilitySettings>,
     mut commands: Commands,
     #[cfg(not(target_arch = "wasm32"))] mut exit: MessageWriter<AppExit>,
 ) {
@@ -136,6 +400,55 @@ fn button_actions(
             MenuButton::Start => {
                 next_state.set(Sections::Chase);
             }
+            MenuButton::Difficulty => {
+                *difficulty = difficulty.next();
+            }
+            MenuButton::Brightness => {
+                display.brightness = display.next_brightness();
+            }
+            MenuButton::Fov => {
+                camera.fov = camera.next_fov();
+            }
+            MenuButton::Fullscreen => {
+                display.fullscreen = !display.fullscreen;
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            MenuButton::FrameLimit => {
+                display.frame_limit = display.frame_limit.next();
+            }
+            MenuButton::Quality => {
+                *quality = quality.next();
+            }
+            MenuButton::Slot => {
+                history.next_slot();
+            }
+            MenuButton::History => {
+                spawn_history_overlay(&mut commands, &history);
+            }
+            MenuButton::Accessibility => {
+                spawn_accessibility_overlay(&mut commands);
+            }
+            MenuButton::HeadBob => {
+                input.head_bob = !input.head_bob;
+            }
+            MenuButton::Rumble => {
+                accessibility.no_haptics = !accessibility.no_haptics;
+            }
+            MenuButton::SoftFail => {
+                accessibility.no_soft_fail = !accessibility.no_soft_fail;
+            }
+            MenuButton::NpcCorruption => {
+                accessibility.no_npc_corruption = !accessibility.no_npc_corruption;
+            }
+            MenuButton::ReducedMotion => {
+                accessibility.reduced_motion = !accessibility.reduced_motion;
+            }
+            MenuButton::HighContrastChevron => {
+                accessibility.high_contrast_chevron = !accessibility.high_contrast_chevron;
+            }
+            MenuButton::Controls => {
+                spawn_controls_overlay(&mut commands);
+            }
             MenuButton::Credits => {
                 spawn_credits_overlay(&mut commands);
             }
@@ -150,24 +463,290 @@ fn button_actions(
 fn spawn_credits_overlay(commands: &mut Commands) {
     commands
         .spawn((
+            MenuOverlay,
             CreditsOverlay,
             DespawnOnExit(Sections::Menu),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.92)),
+            GlobalZIndex(200),
+        ))
+        .with_children(|parent| {
+            // Scrolling column, starting just below the bottom of the screen.
+            parent
+                .spawn((
+                    CreditsScroll::default(),
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(16.0),
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        ..default()
+                    },
+                ))
+                .with_children(|column| {
+                    column.spawn((
+                        Text::new("Credits"),
+                        TextFont {
+                            font_size: 36.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    let lines = [
+                        "A game by TM Storey",
+                        "",
+                        "Thanks to Quaternius for many assets and animations",
+                        "",
+                        "Made with Bevy",
+                        "For Bevy Jam #7",
+                        "",
+                        "Based on the many problems with generative AI",
+                    ];
+                    for line in lines {
+                        column.spawn((
+                            Text::new(line),
+                            TextFont {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.8, 0.8, 0.8, 1.0)),
+                        ));
+                    }
+                });
+
+            // Back button, pinned to the bottom so it can dismiss early.
+            spawn_back_button(parent);
+        });
+}
+
+/// Back button pinned to the bottom of an overlay.
+fn spawn_back_button(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(32.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Button,
+                Node {
+                    width: Val::Px(120.0),
+                    height: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+                BackgroundColor(NORMAL_BUTTON),
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("Back"),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+/// Short display name for a key, e.g. `KeyP` -> "P".
+fn key_name(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.strip_prefix("Key").unwrap_or(&name).to_string()
+}
+
+fn spawn_controls_overlay(commands: &mut Commands) {
+    let bindings = [
+        ("Click".to_string(), "Capture the mouse and look around"),
+        ("Escape".to_string(), "Release the mouse"),
+        ("W / S".to_string(), "Walk forward / back"),
+        ("Ctrl".to_string(), "Crouch"),
+        (key_name(AUTO_CAMERA_KEY), "Auto camera in the chase"),
+        (key_name(THIRD_PERSON_KEY), "Toggle third person"),
+        (key_name(PHOTO_KEY), "Photo mode (WASD, Q/E to fly)"),
+        (key_name(SCREENSHOT_KEY), "Save a screenshot"),
+        (key_name(TIMER_KEY), "Show the run timer"),
+        (key_name(FULLSCREEN_KEY), "Toggle fullscreen"),
+        (key_name(MENU_KEY), "Return to the menu"),
+    ];
+
+    commands
+        .spawn((
+            MenuOverlay,
+            DespawnOnExit(Sections::Menu),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.92)),
+            GlobalZIndex(200),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Controls"),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            for (key, action) in bindings {
+                parent
+                    .spawn(Node {
+                        width: Val::Px(480.0),
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(key),
+                            TextFont {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        row.spawn((
+                            Text::new(action),
+                            TextFont {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.8, 0.8, 0.8, 1.0)),
+                        ));
+                    });
+            }
+
+            spawn_back_button(parent);
+        });
+}
+
+/// Toggles for players sensitive to motion or who struggle to read the chase.
+fn spawn_accessibility_overlay(commands: &mut Commands) {
+    commands
+        .spawn((
+            MenuOverlay,
+            DespawnOnExit(Sections::Menu),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
                 position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 99.)),
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.92)),
             GlobalZIndex(200),
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Credits"),
+                Text::new("Accessibility"),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            spawn_setting_button(parent, MenuButton::HeadBob, SettingLabel::HeadBob);
+
+            spawn_setting_button(
+                parent,
+                MenuButton::HighContrastChevron,
+                SettingLabel::HighContrastChevron,
+            );
+
+            spawn_setting_button(
+                parent,
+                MenuButton::ReducedMotion,
+                SettingLabel::ReducedMotion,
+            );
+
+            spawn_setting_button(parent, MenuButton::Rumble, SettingLabel::Rumble);
+
+            spawn_setting_button(parent, MenuButton::SoftFail, SettingLabel::SoftFail);
+
+            spawn_setting_button(
+                parent,
+                MenuButton::NpcCorruption,
+                SettingLabel::NpcCorruption,
+            );
+
+            spawn_back_button(parent);
+        });
+}
+
+/// The selected slot's runs, newest first.
+fn spawn_history_overlay(commands: &mut Commands, history: &RunHistory) {
+    let mut lines: Vec<String> = history
+        .runs()
+        .iter()
+        .rev()
+        .map(|run| {
+            format!(
+                "{:?}  {}  chevrons {}  dream {}%  rotations {}{}",
+                run.ending,
+                format_time(run.time),
+                run.chevron_count,
+                (run.peak_dream_intensity * 100.0).round(),
+                run.total_rotations,
+                if run.looked_behind {
+                    "  looked behind"
+                } else {
+                    ""
+                }
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push("No finished runs yet".to_string());
+    }
+
+    commands
+        .spawn((
+            MenuOverlay,
+            DespawnOnExit(Sections::Menu),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.92)),
+            GlobalZIndex(200),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("History: slot {}", history.slot + 1)),
                 TextFont {
                     font_size: 36.0,
                     ..default()
@@ -175,16 +754,6 @@ fn spawn_credits_overlay(commands: &mut Commands) {
                 TextColor(Color::WHITE),
             ));
 
-            let lines = [
-                "A game by TM Storey",
-                "",
-                "Thanks to Quaternius for many assets and animations",
-                "",
-                "Made with Bevy",
-                "For Bevy Jam #7",
-                "",
-                "Based on the many problems with generative AI",
-            ];
             for line in lines {
                 parent.spawn((
                     Text::new(line),
@@ -196,41 +765,38 @@ fn spawn_credits_overlay(commands: &mut Commands) {
                 ));
             }
 
-            // Back button.
-            parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(120.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        border: UiRect::all(Val::Px(2.0)),
-                        margin: UiRect::top(Val::Px(24.0)),
-                        ..default()
-                    },
-                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.3)),
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_children(|btn| {
-                    btn.spawn((
-                        Text::new("Back"),
-                        TextFont {
-                            font_size: 20.0,
-                            ..default()
-                        },
-                        TextColor(Color::WHITE),
-                    ));
-                });
+            spawn_back_button(parent);
         });
 }
 
-fn credits_back(
+/// Scroll the credits upward; close the overlay once they've left the screen,
+/// if the scroll auto-closes.
+fn credits_scroll(
     mut commands: Commands,
+    time: Res<Time>,
+    mut columns: Query<(&mut Node, &mut CreditsScroll)>,
     overlay: Query<Entity, With<CreditsOverlay>>,
+) {
+    for (mut node, mut scroll) in &mut columns {
+        scroll.elapsed += time.delta_secs();
+        let t = (scroll.elapsed / CREDITS_DURATION).min(1.0);
+        // From just below the screen to fully above it.
+        node.top = Val::Percent(100.0 - 200.0 * t);
+
+        if t >= 1.0 && scroll.auto_close {
+            for entity in &overlay {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+fn overlay_back(
+    mut commands: Commands,
+    overlay: Query<Entity, With<MenuOverlay>>,
     buttons: Query<&Interaction, (Changed<Interaction>, Without<MenuButton>)>,
 ) {
-    // The Back button in the credits overlay has no MenuButton marker.
+    // The Back button in an overlay has no MenuButton marker.
     for interaction in &buttons {
         if *interaction == Interaction::Pressed {
             for entity in &overlay {
@@ -1,24 +1,42 @@
 // NPC that leads the player across the terrain, demonstrating terrain changes.
+use std::time::Duration;
+
 use bevy::prelude::*;
 use bevy::scene::SceneInstanceReady;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
+use crate::chevron::point_chevron_at;
+use crate::dream::DreamSettings;
+use crate::photo::photo_mode_inactive;
 use crate::player::Player;
 use crate::sections::{PlotFlags, Sections};
+use crate::settings::{AccessibilitySettings, Difficulty};
 use crate::terrain::generation::NoiseSampler;
-use crate::terrain::{StaleChunk, TerrainConfig, TerrainNoise, terrain_height};
+use crate::terrain::{StaleChunk, TerrainConfig, TerrainNoise, TerrainSeed, terrain_height};
 
 pub struct NpcPlugin;
 
 impl Plugin for NpcPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (load_npc_assets, spawn_npc_chevron).chain())
+        app.init_resource::<NpcConfig>()
+            .add_systems(Startup, (load_npc_assets, spawn_npc_chevron).chain())
             .add_systems(OnEnter(Sections::Chase), spawn_npc)
+            .add_observer(reset_chevron_on_npc_removed)
             .add_systems(
                 Update,
-                (npc_ai, npc_movement, npc_terrain_follow, update_npc_chevron)
+                (
+                    npc_ai,
+                    npc_movement,
+                    npc_terrain_follow,
+                    update_npc_chevron,
+                    update_npc_corruption.run_if(|accessibility: Res<AccessibilitySettings>| {
+                        !accessibility.no_npc_corruption
+                    }),
+                )
                     .chain()
-                    .run_if(in_state(Sections::Chase)),
+                    .run_if(in_state(Sections::Chase))
+                    .run_if(photo_mode_inactive),
             );
     }
 }
@@ -27,6 +45,7 @@ const NPC_PATH: &str = "character/character.gltf";
 
 // Animation indices (alphabetical order in the GLTF)
 const ANIM_IDLE: usize = 8; // Idle_Loop
+const ANIM_BECKON: usize = 9; // Idle_Talking_Loop
 const ANIM_JOG: usize = 15; // Jog_Fwd_Loop
 const ANIM_SPRINT: usize = 31; // Sprint_Loop
 
@@ -34,15 +53,66 @@ const SPRINT_SPEED: f32 = 9.8;
 const WAYPOINT_REACHED_DIST: f32 = 2.0;
 const CIRCLE_ENTER_DIST: f32 = 8.0;
 const CIRCLE_EXIT_DIST: f32 = 32.0;
-const CIRCLE_RADIUS: f32 = 8.0;
-const CIRCLE_SPEED: f32 = 1.0; // radians per second
 const WAYPOINT_MIN_DIST: f32 = 24.0;
 const WAYPOINT_MAX_DIST: f32 = 48.0;
 /// Max turn angle when picking a new waypoint (90 degrees).
 const MAX_TURN: f32 = std::f32::consts::FRAC_PI_2;
-const IDLE_DIST: f32 = 128.0;
-const CHEVRON_SHOW_DIST: f32 = 32.0;
-const CHEVRON_MARGIN: f32 = 40.0;
+/// Mixed into the terrain seed so the NPC's draws don't mirror the terrain's.
+const NPC_RNG_SALT: u64 = 0x9e37_79b9_7f4a_7c15;
+pub(crate) const IDLE_DIST: f32 = 128.0;
+/// The chevron appears once the NPC in front is further than this...
+const CHEVRON_SHOW_DIST: f32 = 34.0;
+/// ...and goes again only once they're back within this. The gap keeps it from
+/// flickering, and bumping `chevron_count`, while the distance hovers near one.
+const CHEVRON_HIDE_DIST: f32 = 30.0;
+/// Outline width of the high-contrast chevron.
+const CHEVRON_OUTLINE: f32 = 2.0;
+/// Dream intensity at which the NPC starts to destabilise.
+const CORRUPTION_START: f32 = 0.3;
+/// Share of the colour drained toward grey at full intensity.
+const CORRUPTION_DESATURATE: f32 = 0.85;
+/// Deepest the NPC flickers toward transparent at full intensity.
+const CORRUPTION_FLICKER: f32 = 0.7;
+const CORRUPTION_GLOW: LinearRgba = LinearRgba::rgb(0.15, 0.2, 0.35);
+/// Crossfade time between run cycles.
+const ANIM_BLEND: Duration = Duration::from_millis(400);
+
+/// How the NPC behaves when the player reaches it.
+#[derive(Resource, Clone, Debug)]
+pub struct NpcConfig {
+    /// Seconds the NPC stops, turns to the player and gestures before running
+    /// on ahead: "come, follow me", e.g. `Some(2.5)`. `None`, the default,
+    /// has it circle the player instead, until they drift apart.
+    pub beckon: Option<f32>,
+    pub circle_radius: f32,
+    /// Radians per second around the player.
+    pub circle_speed: f32,
+    /// Clockwise seen from above, otherwise counter-clockwise.
+    pub circle_clockwise: bool,
+}
+
+impl Default for NpcConfig {
+    fn default() -> Self {
+        Self {
+            beckon: None,
+            circle_radius: 8.0,
+            circle_speed: 1.0,
+            circle_clockwise: true,
+        }
+    }
+}
+
+impl NpcConfig {
+    /// Circle speed signed for the direction. Angles are measured on the
+    /// XZ plane from +X toward +Z, which is clockwise seen from above.
+    fn signed_circle_speed(&self) -> f32 {
+        if self.circle_clockwise {
+            self.circle_speed
+        } else {
+            -self.circle_speed
+        }
+    }
+}
 
 #[derive(Component)]
 pub struct Npc;
@@ -55,16 +125,30 @@ enum NpcState {
     Idle,
     Wandering,
     Circling { angle: f32 },
+    Beckoning { remaining: f32 },
 }
 
 #[derive(Component)]
 struct NpcHeading(f32);
 
+/// One of the NPC's meshes, on its own copy of its material so the corruption
+/// can be driven without touching the shared GLTF asset.
+#[derive(Component)]
+struct CorruptibleMaterial {
+    base_color: Color,
+    alpha_mode: AlphaMode,
+}
+
+/// Seeded RNG for the NPC's waypoints.
+#[derive(Resource)]
+struct NpcRng(StdRng);
+
 /// Stores the animation graph and node indices for the NPC.
 #[derive(Component)]
 struct NpcAnimations {
     graph: Handle<AnimationGraph>,
     idle: AnimationNodeIndex,
+    beckon: AnimationNodeIndex,
     jog: AnimationNodeIndex,
     sprint: AnimationNodeIndex,
 }
@@ -86,6 +170,11 @@ fn load_npc_assets(
         1.0,
         graph.root,
     );
+    let beckon = graph.add_clip(
+        asset_server.load(GltfAssetLabel::Animation(ANIM_BECKON).from_asset(NPC_PATH)),
+        1.0,
+        graph.root,
+    );
     let jog = graph.add_clip(
         asset_server.load(GltfAssetLabel::Animation(ANIM_JOG).from_asset(NPC_PATH)),
         1.0,
@@ -104,13 +193,17 @@ fn load_npc_assets(
         animations: NpcAnimations {
             graph: graph_handle,
             idle,
+            beckon,
             jog,
             sprint,
         },
     });
 }
 
-fn spawn_npc(mut commands: Commands, assets: Res<NpcAssets>) {
+fn spawn_npc(mut commands: Commands, assets: Res<NpcAssets>, seed: Res<TerrainSeed>) {
+    // Fresh each run, so the NPC's route follows from the seed and the
+    // player's path alone.
+    commands.insert_resource(NpcRng(StdRng::seed_from_u64(seed.0 as u64 ^ NPC_RNG_SALT)));
     // Spawn ahead of the player start position (player starts at 0, 10, 0 facing -Z)
     let initial_heading = std::f32::consts::PI; // facing -Z
     commands
@@ -122,7 +215,36 @@ fn spawn_npc(mut commands: Commands, assets: Res<NpcAssets>) {
             SceneRoot(assets.scene.clone()),
             Transform::from_xyz(0.0, 10.0, -12.0),
         ))
-        .observe(start_animation);
+        .observe(start_animation)
+        .observe(own_npc_materials);
+}
+
+fn own_npc_materials(
+    trigger: On<SceneInstanceReady>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    meshes: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    accessibility: Res<AccessibilitySettings>,
+) {
+    if accessibility.no_npc_corruption {
+        return;
+    }
+    for child in children.iter_descendants(trigger.entity) {
+        let Ok(material) = meshes.get(child) else {
+            continue;
+        };
+        let Some(owned) = materials.get(&material.0).cloned() else {
+            continue;
+        };
+        commands.entity(child).insert((
+            CorruptibleMaterial {
+                base_color: owned.base_color,
+                alpha_mode: owned.alpha_mode,
+            },
+            MeshMaterial3d(materials.add(owned)),
+        ));
+    }
 }
 
 fn start_animation(
@@ -135,10 +257,14 @@ fn start_animation(
     let entity = _trigger.entity;
     for child in children.iter_descendants(entity) {
         if let Ok(mut player) = players.get_mut(child) {
-            player.play(npc_assets.animations.sprint).repeat();
+            let mut transitions = AnimationTransitions::new();
+            transitions
+                .play(&mut player, npc_assets.animations.sprint, Duration::ZERO)
+                .repeat();
             commands
                 .entity(child)
-                .insert(AnimationGraphHandle(npc_assets.animations.graph.clone()));
+                .insert(AnimationGraphHandle(npc_assets.animations.graph.clone()))
+                .insert(transitions);
             break;
         }
     }
@@ -150,7 +276,11 @@ fn npc_ai(
     npc_assets: Res<NpcAssets>,
     children: Query<&Children>,
     npc_entities: Query<Entity, With<Npc>>,
-    mut players: Query<&mut AnimationPlayer>,
+    mut players: Query<(&mut AnimationPlayer, &mut AnimationTransitions)>,
+    mut rng: ResMut<NpcRng>,
+    config: Res<NpcConfig>,
+    time: Res<Time>,
+    mut led_on: Local<bool>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
@@ -168,10 +298,16 @@ fn npc_ai(
 
     let mut switch_animation = None;
 
+    // After beckoning the NPC runs on from right beside the player, so it
+    // can't catch up again until the player has fallen behind once.
+    if dist_to_player > CIRCLE_ENTER_DIST {
+        *led_on = false;
+    }
+
     match *state {
         NpcState::Idle => {
             if dist_to_player < IDLE_DIST {
-                target.0 = pick_waypoint(npc_pos, heading.0);
+                target.0 = pick_waypoint(&mut rng.0, npc_pos, heading.0);
                 *state = NpcState::Wandering;
                 switch_animation = Some(npc_assets.animations.sprint);
             }
@@ -180,15 +316,20 @@ fn npc_ai(
             if dist_to_player > IDLE_DIST {
                 *state = NpcState::Idle;
                 switch_animation = Some(npc_assets.animations.idle);
-            } else if dist_to_player < CIRCLE_ENTER_DIST {
-                let offset = npc_pos - player_pos;
-                let angle = offset.y.atan2(offset.x);
-                *state = NpcState::Circling { angle };
-                switch_animation = Some(npc_assets.animations.jog);
+            } else if dist_to_player < CIRCLE_ENTER_DIST && !*led_on {
+                if let Some(seconds) = config.beckon {
+                    *state = NpcState::Beckoning { remaining: seconds };
+                    switch_animation = Some(npc_assets.animations.beckon);
+                } else {
+                    let offset = npc_pos - player_pos;
+                    let angle = offset.y.atan2(offset.x);
+                    *state = NpcState::Circling { angle };
+                    switch_animation = Some(npc_assets.animations.jog);
+                }
             } else {
                 let dist_to_target = npc_pos.distance(target.0);
                 if dist_to_target < WAYPOINT_REACHED_DIST {
-                    target.0 = pick_waypoint(npc_pos, heading.0);
+                    target.0 = pick_waypoint(&mut rng.0, npc_pos, heading.0);
                 }
             }
         }
@@ -196,8 +337,21 @@ fn npc_ai(
             if dist_to_player > CIRCLE_EXIT_DIST {
                 let away = (npc_pos - player_pos).normalize_or_zero();
                 heading.0 = away.y.atan2(away.x);
-                target.0 = pick_waypoint(npc_pos, heading.0);
+                target.0 = pick_waypoint(&mut rng.0, npc_pos, heading.0);
+                *state = NpcState::Wandering;
+                switch_animation = Some(npc_assets.animations.sprint);
+            }
+        }
+        NpcState::Beckoning { ref mut remaining } => {
+            *remaining -= time.delta_secs();
+            if *remaining <= 0.0 {
+                // Lead on the way it was already going, not back past the
+                // player.
+                let away = (npc_pos - player_pos).normalize_or_zero();
+                heading.0 = away.y.atan2(away.x);
+                target.0 = pick_waypoint(&mut rng.0, npc_pos, heading.0);
                 *state = NpcState::Wandering;
+                *led_on = true;
                 switch_animation = Some(npc_assets.animations.sprint);
             }
         }
@@ -207,9 +361,10 @@ fn npc_ai(
     if let Some(anim_index) = switch_animation {
         if let Ok(npc_entity) = npc_entities.single() {
             for child in children.iter_descendants(npc_entity) {
-                if let Ok(mut player) = players.get_mut(child) {
-                    player.stop_all();
-                    player.play(anim_index).repeat();
+                if let Ok((mut player, mut transitions)) = players.get_mut(child) {
+                    transitions
+                        .play(&mut player, anim_index, ANIM_BLEND)
+                        .repeat();
                     break;
                 }
             }
@@ -220,6 +375,8 @@ fn npc_ai(
 fn npc_movement(
     mut query: Query<(&mut Transform, &mut NpcState, &NpcTarget, &mut NpcHeading), With<Npc>>,
     player_query: Query<&Transform, (With<Player>, Without<Npc>)>,
+    difficulty: Res<Difficulty>,
+    config: Res<NpcConfig>,
     time: Res<Time>,
 ) {
     let Ok((mut transform, mut state, target, mut heading)) = query.single_mut() else {
@@ -235,7 +392,7 @@ fn npc_movement(
             let dir = (target.0 - npc_pos).normalize_or_zero();
             if dir != Vec2::ZERO {
                 heading.0 = dir.y.atan2(dir.x);
-                let movement = dir * SPRINT_SPEED * dt;
+                let movement = dir * SPRINT_SPEED * difficulty.npc_speed_mul * dt;
                 transform.translation.x += movement.x;
                 transform.translation.z += movement.y;
                 // Face movement direction (Bevy's forward is -Z, so rotate accordingly)
@@ -252,15 +409,31 @@ fn npc_movement(
                 player_transform.translation.z,
             );
 
-            *angle += CIRCLE_SPEED * dt;
-            let circle_pos = player_pos + Vec2::new(angle.cos(), angle.sin()) * CIRCLE_RADIUS;
+            let speed = config.signed_circle_speed();
+            *angle += speed * dt;
+            let circle_pos =
+                player_pos + Vec2::new(angle.cos(), angle.sin()) * config.circle_radius;
             transform.translation.x = circle_pos.x;
             transform.translation.z = circle_pos.y;
-            // Face tangent to the circle (perpendicular to the radius).
-            let tangent_angle = *angle + std::f32::consts::FRAC_PI_2;
-            heading.0 = tangent_angle;
+            // Face tangent to the circle (perpendicular to the radius), along
+            // the way it's going.
+            heading.0 = *angle + std::f32::consts::FRAC_PI_2.copysign(speed);
             transform.rotation = Quat::from_rotation_y(-heading.0 + std::f32::consts::FRAC_PI_2);
         }
+        NpcState::Beckoning { .. } => {
+            let Ok(player_transform) = player_query.single() else {
+                return;
+            };
+            let to_player = Vec2::new(
+                player_transform.translation.x,
+                player_transform.translation.z,
+            ) - npc_pos;
+            if to_player != Vec2::ZERO {
+                heading.0 = to_player.y.atan2(to_player.x);
+                transform.rotation =
+                    Quat::from_rotation_y(-heading.0 + std::f32::consts::FRAC_PI_2);
+            }
+        }
     }
 }
 
@@ -303,19 +476,62 @@ fn spawn_npc_chevron(mut commands: Commands) {
             position_type: PositionType::Absolute,
             ..default()
         },
+        BackgroundColor(Color::NONE),
+        BorderColor::all(Color::NONE),
         Visibility::Hidden,
     ));
 }
 
+/// The chevron only means something while the NPC exists. As soon as it is
+/// despawned, hide the chevron and clear any shake or danger styling, so no
+/// stray frame of it renders during the section change.
+fn reset_chevron_on_npc_removed(
+    _removed: On<Remove, Npc>,
+    mut commands: Commands,
+    chevron: Query<Entity, With<NpcChevron>>,
+) {
+    for entity in &chevron {
+        commands.entity(entity).insert((
+            Text::new("v"),
+            TextColor(Color::WHITE),
+            BackgroundColor(Color::NONE),
+            UiTransform::default(),
+            Visibility::Hidden,
+        ));
+    }
+}
+
 fn update_npc_chevron(
-    mut chevron: Query<(&mut Node, &mut UiTransform, &mut Visibility), With<NpcChevron>>,
+    mut chevron: Query<
+        (
+            &mut Node,
+            &mut UiTransform,
+            &mut BorderColor,
+            &mut Visibility,
+        ),
+        With<NpcChevron>,
+    >,
     npc_query: Query<&GlobalTransform, With<Npc>>,
     camera_query: Query<(&Camera, &GlobalTransform), With<Player>>,
+    accessibility: Res<AccessibilitySettings>,
     mut flags: ResMut<PlotFlags>,
 ) {
-    let Ok((mut node, mut chevron_transform, mut visibility)) = chevron.single_mut() else {
+    let Ok((mut node, mut chevron_transform, mut border_color, mut visibility)) =
+        chevron.single_mut()
+    else {
         return;
     };
+
+    // Outline the chevron in high-contrast mode so it reads against bright terrain.
+    if accessibility.high_contrast_chevron {
+        node.border = UiRect::all(Val::Px(CHEVRON_OUTLINE));
+        node.padding = UiRect::horizontal(Val::Px(CHEVRON_OUTLINE * 3.0));
+        *border_color = BorderColor::all(Color::WHITE);
+    } else {
+        node.border = UiRect::ZERO;
+        node.padding = UiRect::ZERO;
+        *border_color = BorderColor::all(Color::NONE);
+    }
     let Ok(npc_global) = npc_query.single() else {
         *visibility = Visibility::Hidden;
         return;
@@ -329,53 +545,24 @@ fn update_npc_chevron(
     let cam_pos = camera_global.translation();
     let dist = Vec2::new(npc_world.x - cam_pos.x, npc_world.z - cam_pos.z).length();
 
-    let Some(viewport_size) = camera.logical_viewport_size() else {
+    let Some(mut placement) = point_chevron_at(npc_world, camera, camera_global) else {
         return;
     };
-    let center = viewport_size / 2.0;
-
-    // Transform NPC position into camera view space to check if in front or behind.
-    let view_matrix = camera_global.affine().inverse();
-    let npc_view = view_matrix.transform_point3(npc_world);
-
-    // In Bevy's view space, camera looks down -Z, so npc_view.z < 0 means in front.
-    let screen_pos = if npc_view.z < 0.0 {
-        // NPC is in front of camera - project to screen
-        if dist < CHEVRON_SHOW_DIST {
+    if placement.in_front {
+        // Close enough ahead to follow without help.
+        let cutoff = if *visibility == Visibility::Hidden {
+            CHEVRON_SHOW_DIST
+        } else {
+            CHEVRON_HIDE_DIST
+        };
+        if dist < cutoff {
             *visibility = Visibility::Hidden;
             return;
         }
-        if let Ok(vp) = camera.world_to_viewport(camera_global, npc_world) {
-            vp
-        } else {
-            center
-        }
-    } else {
-        // NPC is behind camera - flip the direction so chevron points correctly
-        Vec2::new(npc_view.x, npc_view.y).normalize_or_zero() * center.x.min(center.y) + center
-    };
-
-    if npc_view.z < 0.0 {
-        // NPC is in front - place chevron at projected position, no rotation.
-        let clamped_x = screen_pos
-            .x
-            .clamp(CHEVRON_MARGIN, viewport_size.x - CHEVRON_MARGIN);
-        let clamped_y = screen_pos
-            .y
-            .clamp(CHEVRON_MARGIN, viewport_size.y - CHEVRON_MARGIN);
-        node.left = Val::Px(clamped_x - 16.0);
-        node.top = Val::Px(clamped_y - 16.0);
-        chevron_transform.rotation = Rot2::IDENTITY;
-    } else {
-        // NPC is behind - place chevron partway from center toward the edge, rotated.
-        let dir = (screen_pos - center).normalize_or_zero();
-        let edge_dist = center.x.min(center.y) * 0.5;
-        let pos = center + dir * edge_dist;
-        node.left = Val::Px(pos.x - 16.0);
-        node.top = Val::Px(pos.y - 16.0);
-        let angle = dir.y.atan2(dir.x);
-        chevron_transform.rotation = Rot2::radians(angle - std::f32::consts::FRAC_PI_2);
+        // In front, the chevron sits over the NPC rather than pointing.
+        placement.rotation = Rot2::IDENTITY;
     }
+    placement.apply(&mut node, &mut chevron_transform);
 
     if *visibility == Visibility::Hidden {
         flags.chevron_count += 1;
@@ -384,10 +571,58 @@ fn update_npc_chevron(
     *visibility = Visibility::Inherited;
 }
 
+/// Drain the NPC's colour, add a cold glow and make her flicker, all scaled by
+/// how deep the dream is. Nothing below `CORRUPTION_START`, strongest right as
+/// she vanishes.
+///
+/// Getting a material mutably marks it changed and re-prepares it, so the
+/// write is skipped while the look holds still, as it does below
+/// `CORRUPTION_START`. Newly owned materials always get one.
+fn update_npc_corruption(
+    time: Res<Time>,
+    dream: Query<&DreamSettings, With<Player>>,
+    meshes: Query<(&CorruptibleMaterial, &MeshMaterial3d<StandardMaterial>)>,
+    added: Query<(), Added<CorruptibleMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut applied: Local<Option<(f32, f32)>>,
+) {
+    let intensity = dream.single().map_or(0.0, |d| d.intensity);
+    let strength = ((intensity - CORRUPTION_START) / (1.0 - CORRUPTION_START)).clamp(0.0, 1.0);
+    let strength = strength * strength;
+
+    // Two detuned waves so the flicker stutters instead of pulsing evenly.
+    let t = time.elapsed_secs();
+    let wave = ((t * 23.0).sin() * (t * 7.3).sin()).abs();
+    let alpha = 1.0 - CORRUPTION_FLICKER * strength * wave;
+    if *applied == Some((strength, alpha)) && added.is_empty() {
+        return;
+    }
+    *applied = Some((strength, alpha));
+
+    for (corruptible, material) in &meshes {
+        let Some(material) = materials.get_mut(&material.0) else {
+            continue;
+        };
+        let base = corruptible.base_color.to_linear();
+        let grey = base.luminance();
+        let drained = base.mix(
+            &LinearRgba::new(grey, grey, grey, base.alpha),
+            CORRUPTION_DESATURATE * strength,
+        );
+        material.base_color = Color::from(drained).with_alpha(base.alpha * alpha);
+        material.emissive = CORRUPTION_GLOW * strength;
+        // Only pay for blending once she can actually flicker.
+        material.alpha_mode = if strength > 0.0 {
+            AlphaMode::Blend
+        } else {
+            corruptible.alpha_mode
+        };
+    }
+}
+
 /// Pick a random waypoint within MAX_TURN of the current heading, at a distance
 /// between WAYPOINT_MIN_DIST and WAYPOINT_MAX_DIST.
-fn pick_waypoint(pos: Vec2, heading: f32) -> Vec2 {
-    let mut rng = rand::rng();
+fn pick_waypoint(rng: &mut impl Rng, pos: Vec2, heading: f32) -> Vec2 {
     let turn: f32 = rng.random_range(-MAX_TURN..=MAX_TURN);
     let dist: f32 = rng.random_range(WAYPOINT_MIN_DIST..=WAYPOINT_MAX_DIST);
     let angle = heading + turn;
@@ -0,0 +1,214 @@
+// Ambient particles: dream motes in the chase and falling embers in the
+// underworld. A fixed pool of billboarded quads per section, recycled around
+// the player instead of spawned and despawned.
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dream::DreamSettings;
+use crate::photo::photo_mode_inactive;
+use crate::player::Player;
+use crate::sections::Sections;
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ParticleRng(StdRng::seed_from_u64(0)))
+            .add_systems(
+                OnEnter(Sections::Chase),
+                (|| ParticleKind::Mote).pipe(spawn_pool),
+            )
+            .add_systems(
+                OnEnter(Sections::Underworld),
+                (|| ParticleKind::Ember).pipe(spawn_pool),
+            )
+            .add_systems(
+                Update,
+                (
+                    mote_density.run_if(in_state(Sections::Chase)),
+                    drift_particles,
+                )
+                    .chain()
+                    .run_if(photo_mode_inactive),
+            );
+    }
+}
+
+/// Pool size for chase motes; how many show scales with dream intensity.
+const MAX_MOTES: usize = 200;
+/// Motes visible at dream intensity 0.
+const MIN_MOTES: usize = 20;
+const MOTE_SIZE: f32 = 0.05;
+/// Half-extent of the box around the player that motes live in.
+const MOTE_RANGE: f32 = 12.0;
+
+const EMBER_COUNT: usize = 60;
+const EMBER_SIZE: f32 = 0.03;
+/// Embers stay close to the candle.
+const EMBER_RANGE: f32 = 4.0;
+const EMBER_FALL_SPEED: f32 = 0.3;
+
+#[derive(Resource)]
+struct ParticleRng(StdRng);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParticleKind {
+    Mote,
+    Ember,
+}
+
+impl ParticleKind {
+    fn count(self) -> usize {
+        match self {
+            ParticleKind::Mote => MAX_MOTES,
+            ParticleKind::Ember => EMBER_COUNT,
+        }
+    }
+
+    fn range(self) -> f32 {
+        match self {
+            ParticleKind::Mote => MOTE_RANGE,
+            ParticleKind::Ember => EMBER_RANGE,
+        }
+    }
+
+    fn section(self) -> Sections {
+        match self {
+            ParticleKind::Mote => Sections::Chase,
+            ParticleKind::Ember => Sections::Underworld,
+        }
+    }
+
+    /// A fresh velocity for a newly placed particle.
+    fn velocity(self, rng: &mut impl Rng) -> Vec3 {
+        match self {
+            ParticleKind::Mote => Vec3::new(
+                rng.random_range(-0.2..0.2),
+                rng.random_range(-0.05..0.15),
+                rng.random_range(-0.2..0.2),
+            ),
+            ParticleKind::Ember => Vec3::new(
+                rng.random_range(-0.05..0.05),
+                -EMBER_FALL_SPEED * rng.random_range(0.5..1.5),
+                rng.random_range(-0.05..0.05),
+            ),
+        }
+    }
+}
+
+#[derive(Component)]
+struct Particle {
+    kind: ParticleKind,
+    velocity: Vec3,
+    /// Phase for the sideways sway, so neighbours don't move in lockstep.
+    phase: f32,
+}
+
+fn random_offset(rng: &mut impl Rng, range: f32) -> Vec3 {
+    Vec3::new(
+        rng.random_range(-range..range),
+        rng.random_range(-range..range),
+        rng.random_range(-range..range),
+    )
+}
+
+fn spawn_pool(
+    In(kind): In<ParticleKind>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<ParticleRng>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let centre = player.single().map_or(Vec3::ZERO, |t| t.translation);
+    let (size, color) = match kind {
+        ParticleKind::Mote => (MOTE_SIZE, LinearRgba::new(1.0, 0.9, 0.6, 0.5)),
+        ParticleKind::Ember => (EMBER_SIZE, LinearRgba::new(1.0, 0.4, 0.1, 1.0)),
+    };
+    let mesh = meshes.add(Rectangle::new(size, size));
+    let material = materials.add(StandardMaterial {
+        base_color: color.into(),
+        unlit: true,
+        alpha_mode: AlphaMode::Add,
+        ..default()
+    });
+
+    for _ in 0..kind.count() {
+        let offset = random_offset(&mut rng.0, kind.range());
+        commands.spawn((
+            Particle {
+                kind,
+                velocity: kind.velocity(&mut rng.0),
+                phase: rng.0.random_range(0.0..std::f32::consts::TAU),
+            },
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(centre + offset),
+            DespawnOnExit(kind.section()),
+        ));
+    }
+}
+
+/// Show more of the mote pool as the dream deepens.
+fn mote_density(dream: Query<&DreamSettings>, mut motes: Query<(&Particle, &mut Visibility)>) {
+    let intensity = dream.single().map_or(0.0, |d| d.intensity);
+    let shown = MIN_MOTES + ((MAX_MOTES - MIN_MOTES) as f32 * intensity) as usize;
+    for (i, (_, mut visibility)) in motes
+        .iter_mut()
+        .filter(|(p, _)| p.kind == ParticleKind::Mote)
+        .enumerate()
+    {
+        visibility.set_if_neq(if i < shown {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+/// Move particles, face them at the camera and wrap any that leave their box
+/// back in on the opposite side.
+fn drift_particles(
+    time: Res<Time>,
+    mut rng: ResMut<ParticleRng>,
+    player: Query<&Transform, (With<Player>, Without<Particle>)>,
+    mut particles: Query<(&mut Particle, &mut Transform)>,
+) {
+    let Ok(camera) = player.single() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    let t = time.elapsed_secs();
+
+    for (mut particle, mut transform) in &mut particles {
+        let sway = Vec3::new(
+            (t + particle.phase).sin(),
+            0.0,
+            (t * 0.7 + particle.phase).cos(),
+        ) * 0.1;
+        transform.translation += (particle.velocity + sway) * dt;
+        transform.rotation = camera.rotation;
+
+        let range = particle.kind.range();
+        let offset = transform.translation - camera.translation;
+        if offset.abs().max_element() <= range {
+            continue;
+        }
+        match particle.kind {
+            // Wrap around so the density around the player stays even.
+            ParticleKind::Mote => {
+                let wrapped = (offset + range).rem_euclid(Vec3::splat(2.0 * range)) - range;
+                transform.translation = camera.translation + wrapped;
+            }
+            // Embers that have fallen out restart from above.
+            ParticleKind::Ember => {
+                let mut offset = random_offset(&mut rng.0, range);
+                offset.y = range;
+                transform.translation = camera.translation + offset;
+                particle.velocity = ParticleKind::Ember.velocity(&mut rng.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// Photo mode: pauses gameplay, hides UI and lets the camera fly freely.
+use bevy::prelude::*;
+
+use crate::player::{Player, PlayerLook};
+use crate::sections::Sections;
+
+pub struct PhotoPlugin;
+
+impl Plugin for PhotoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoMode>().add_systems(
+            Update,
+            (toggle_photo_mode, photo_fly.run_if(in_photo_mode))
+                .chain()
+                .run_if(
+                    in_state(Sections::Chase)
+                        .or(in_state(Sections::Underworld))
+                        .or(in_state(Sections::Stairs)),
+                ),
+        );
+    }
+}
+
+pub(crate) const PHOTO_KEY: KeyCode = KeyCode::KeyP;
+const FLY_SPEED: f32 = 15.0;
+
+/// Whether photo mode is active, and where to put the camera back afterwards.
+#[derive(Resource, Default)]
+pub struct PhotoMode {
+    pub active: bool,
+    saved: Option<SavedCamera>,
+}
+
+struct SavedCamera {
+    transform: Transform,
+    yaw: f32,
+    pitch: f32,
+}
+
+/// Remembers the visibility a UI root had before photo mode hid it.
+#[derive(Component)]
+struct PhotoHidden(Visibility);
+
+/// Run condition for gameplay systems that should freeze during photo mode.
+pub fn photo_mode_inactive(photo: Res<PhotoMode>) -> bool {
+    !photo.active
+}
+
+fn in_photo_mode(photo: Res<PhotoMode>) -> bool {
+    photo.active
+}
+
+fn toggle_photo_mode(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut photo: ResMut<PhotoMode>,
+    mut time: ResMut<Time<Virtual>>,
+    mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+    mut ui_roots: Query<
+        (Entity, &mut Visibility, Option<&PhotoHidden>),
+        (With<Node>, Without<ChildOf>),
+    >,
+) {
+    if !keyboard.just_pressed(PHOTO_KEY) {
+        return;
+    }
+    let Ok((mut transform, mut look)) = player.single_mut() else {
+        return;
+    };
+
+    if photo.active {
+        if let Some(saved) = photo.saved.take() {
+            *transform = saved.transform;
+            look.yaw = saved.yaw;
+            look.pitch = saved.pitch;
+        }
+        for (entity, mut visibility, previous) in &mut ui_roots {
+            if let Some(previous) = previous {
+                *visibility = previous.0;
+                commands.entity(entity).remove::<PhotoHidden>();
+            }
+        }
+        time.unpause();
+        photo.active = false;
+    } else {
+        photo.saved = Some(SavedCamera {
+            transform: *transform,
+            yaw: look.yaw,
+            pitch: look.pitch,
+        });
+        for (entity, mut visibility, _) in &mut ui_roots {
+            commands.entity(entity).insert(PhotoHidden(*visibility));
+            *visibility = Visibility::Hidden;
+        }
+        time.pause();
+        photo.active = true;
+    }
+}
+
+/// Free-fly the camera: WASD along the view, Q/E down/up.
+fn photo_fly(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut player: Query<&mut Transform, With<Player>>,
+    time: Res<Time<Real>>,
+) {
+    let Ok(mut transform) = player.single_mut() else {
+        return;
+    };
+
+    let forward = *transform.forward();
+    let right = *transform.right();
+
+    let mut movement = Vec3::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        movement += forward;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        movement -= forward;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        movement += right;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        movement -= right;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        movement += Vec3::Y;
+    }
+    if keyboard.pressed(KeyCode::KeyQ) {
+        movement -= Vec3::Y;
+    }
+
+    transform.translation += movement.normalize_or_zero() * FLY_SPEED * time.delta_secs();
+}
@@ -3,34 +3,65 @@ use std::time::Duration;
 
 // First-person camera controller with mouse look and keyboard movement.
 use crate::dream::DreamSettings;
+use crate::flicker::TorchFlicker;
+use crate::photo::{PhotoMode, photo_mode_inactive};
 use crate::sections::Sections;
+use crate::settings::{AccessibilitySettings, CameraSettings, DisplaySettings, InputSettings};
+use crate::third_person::CameraBoom;
 use bevy::camera::Exposure;
+use bevy::ecs::system::SystemParam;
 use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
 use bevy::scene::SceneInstanceReady;
+use bevy::transform::TransformSystems;
 use bevy::window::{CursorGrabMode, CursorOptions};
-#[cfg(not(target_arch = "wasm32"))]
-use bevy::{
-    light::AtmosphereEnvironmentMapLight,
-    pbr::{Atmosphere, AtmosphereSettings, ScatteringMedium},
-    post_process::bloom::Bloom,
-};
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, (spawn_player, load_arm_assets).chain())
+        let mouse_look = mouse_look.into_configs();
+        let player_movement = player_movement.run_if(photo_mode_inactive);
+        // The debug flythrough steers the player itself.
+        #[cfg(debug_assertions)]
+        let mouse_look = mouse_look.run_if(crate::debug::flythrough_inactive);
+        #[cfg(debug_assertions)]
+        let player_movement = player_movement.run_if(crate::debug::flythrough_inactive);
+
+        app.init_resource::<Stationary>()
+            .init_resource::<PlayerConfig>()
+            .init_resource::<Crouch>()
+            .init_resource::<PlayerInput>()
+            .add_systems(Startup, (spawn_player, load_arm_assets).chain())
             .insert_resource(ClearColor(Color::BLACK))
             .insert_resource(GlobalAmbientLight::NONE)
+            .add_systems(Update, gather_input.in_set(ReadInput))
             .add_systems(
                 Update,
-                (toggle_cursor_grab, mouse_look, player_movement).run_if(
-                    in_state(Sections::Chase)
-                        .or(in_state(Sections::Underworld))
-                        .or(in_state(Sections::Stairs)),
+                (toggle_cursor_grab, mouse_look, player_movement)
+                    .after(ReadInput)
+                    .run_if(
+                        in_state(Sections::Chase)
+                            .or(in_state(Sections::Underworld))
+                            .or(in_state(Sections::Stairs)),
+                    ),
+            )
+            .add_systems(Startup, spawn_look_prompt)
+            .add_systems(Update, update_look_prompt)
+            .add_systems(
+                Update,
+                (
+                    apply_brightness.run_if(resource_changed::<DisplaySettings>),
+                    apply_fov.run_if(resource_changed::<CameraSettings>),
                 ),
             )
+            // Head-bob is layered on top of whatever Update systems set: removed
+            // before them and re-applied just before transforms propagate.
+            .add_systems(PreUpdate, (remove_head_bob, remove_dream_shake))
+            .add_systems(
+                PostUpdate,
+                (apply_head_bob, apply_dream_shake).before(TransformSystems::Propagate),
+            )
             .add_systems(
                 OnEnter(Sections::Chase),
                 (reset_player, spawn_chase_light, set_sky_background),
@@ -39,10 +70,11 @@ impl Plugin for PlayerPlugin {
                 OnEnter(Sections::Underworld),
                 (spawn_torch_arms, set_black_background),
             )
-            .add_systems(
-                OnEnter(Sections::Awaken),
-                (despawn_arms, set_sky_background),
-            );
+            // The arms and their candle only belong to the underworld; leaving
+            // by any route must drop them so the light doesn't follow into the
+            // stairs.
+            .add_systems(OnExit(Sections::Underworld), despawn_arms)
+            .add_systems(OnEnter(Sections::Awaken), set_sky_background);
     }
 }
 
@@ -56,6 +88,87 @@ pub struct PlayerLook {
     pub pitch: f32,
 }
 
+/// Fills `PlayerInput` for the frame. Anything that reads it runs after;
+/// anything that rewrites it, like a replay, runs inside after `gather_input`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReadInput;
+
+/// This frame's movement and look input. Read from the devices, or fed back
+/// from a replay, and the only input the movement systems see.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PlayerInput {
+    pub forward: bool,
+    pub back: bool,
+    pub crouch: bool,
+    /// Mouse movement in pixels. Zero while the cursor is free.
+    pub look: Vec2,
+}
+
+/// Horizontal walking velocity, eased toward the input direction.
+#[derive(Component, Default)]
+pub struct PlayerVelocity(pub Vec3);
+
+/// Additive walking bob applied to the camera after gameplay systems run.
+#[derive(Component, Default)]
+pub struct HeadBob {
+    phase: f32,
+    amplitude: f32,
+    offset: Vec3,
+    last_base: Option<Vec3>,
+}
+
+/// Additive rotation wobble scaled by dream intensity, applied and removed
+/// around gameplay like `HeadBob` so `PlayerLook` never sees it.
+#[derive(Component, Default)]
+pub struct DreamShake {
+    offset: Quat,
+}
+
+/// Body measurements every section's floor-follow reads, so the camera sits at
+/// the same height everywhere.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct PlayerConfig {
+    pub eye_height: f32,
+    /// Eye height when fully crouched.
+    pub crouch_height: f32,
+    /// Walk speed multiplier when fully crouched.
+    pub crouch_speed_mul: f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            eye_height: 1.5,
+            crouch_height: 0.9,
+            crouch_speed_mul: 0.5,
+        }
+    }
+}
+
+/// How far into a crouch the player is, eased from 0 (standing) to 1.
+#[derive(Resource, Default)]
+pub struct Crouch(pub f32);
+
+/// The camera's current height above the floor, crouch included. Anything
+/// that sets the player's Y from a floor height adds this.
+#[derive(SystemParam)]
+pub struct EyeHeight<'w> {
+    config: Res<'w, PlayerConfig>,
+    crouch: Res<'w, Crouch>,
+}
+
+impl EyeHeight<'_> {
+    pub fn get(&self) -> f32 {
+        self.config
+            .eye_height
+            .lerp(self.config.crouch_height, self.crouch.0)
+    }
+}
+
+/// Seconds since the player last walked.
+#[derive(Resource, Default)]
+pub struct Stationary(pub f32);
+
 #[derive(Resource)]
 pub struct ArmAssets {
     pub scene: Handle<Scene>,
@@ -66,48 +179,77 @@ pub struct ArmAssets {
 #[derive(Component)]
 pub struct PlayerArms;
 
-const EYE_HEIGHT: f32 = 1.5;
+/// "Click to look around" hint, shown whenever the mouse isn't captured.
+#[derive(Component)]
+struct LookPrompt;
+
+/// Camera exposure the lighting was tuned against, at brightness 1.0.
+const BASE_EV100: f32 = 10.0;
 const MOUSE_SENSITIVITY: f32 = 0.003;
-const MOVE_SPEED: f32 = 10.0;
-const MAX_PITCH: f32 = 1.3;
+pub(crate) const MOVE_SPEED: f32 = 10.0;
+/// Speed gained per second while a walk key is held.
+const WALK_ACCEL: f32 = 40.0;
+/// Speed shed per second once the keys are released.
+const WALK_DECEL: f32 = 30.0;
+/// Band before the look limit over which mouse look slows to a stop, in
+/// radians, so the view glides into the limit instead of hitting it.
+const PITCH_SOFT_ZONE: f32 = 0.35;
+/// Held to crouch. Either Ctrl works.
+const CROUCH_KEYS: [KeyCode; 2] = [KeyCode::ControlLeft, KeyCode::ControlRight];
+/// Crouch amount gained or lost per second.
+const CROUCH_RATE: f32 = 5.0;
+
+/// Alpha per second at which the look prompt fades in and out.
+const PROMPT_FADE_RATE: f32 = 3.0;
+
+/// Peak vertical bob at full walking speed.
+const BOB_VERTICAL: f32 = 0.05;
+/// Peak side-to-side sway at full walking speed.
+const BOB_LATERAL: f32 = 0.03;
+/// Bob cycles per metre walked.
+const BOB_FREQUENCY: f32 = 0.35;
+/// How quickly the bob amplitude follows the walking speed.
+const BOB_SMOOTHING: f32 = 8.0;
+
+/// How far the candle light dips below full intensity as it flickers.
+const CANDLE_FLICKER: f32 = 0.35;
+
+/// Peak yaw/pitch wobble in radians at full dream intensity.
+const SHAKE_ANGLE: f32 = 0.012;
+
+const DREAM_VIGNETTE: f32 = 0.7;
+const DREAM_GRAIN: f32 = 0.08;
 
 pub const SKY_BLUE: Color = Color::linear_rgb(0.53, 0.81, 0.92);
 
-fn spawn_player(
-    mut commands: Commands,
-    #[cfg(not(target_arch = "wasm32"))] mut scattering_mediums: ResMut<Assets<ScatteringMedium>>,
-) {
-    #[allow(unused_variables)]
-    let camera = commands
-        .spawn((
-            Player,
-            PlayerLook {
-                yaw: 0.0,
-                pitch: 0.0,
-            },
-            Camera3d::default(),
-            Projection::from(PerspectiveProjection {
-                fov: std::f32::consts::FRAC_PI_2 * 0.8,
-                near: 0.01,
-                ..default()
-            }),
-            Exposure { ev100: 10.0 },
-            Transform::from_xyz(0.0, 10.0, 0.0),
-            DreamSettings {
-                intensity: 0.0,
-                time: 0.0,
-                _align: 0.0,
-                _align2: 0.0,
-            },
-        ))
-        .id();
-
-    #[cfg(not(target_arch = "wasm32"))]
-    commands.entity(camera).insert((
-        Atmosphere::earthlike(scattering_mediums.add(ScatteringMedium::default())),
-        AtmosphereSettings::default(),
-        AtmosphereEnvironmentMapLight::default(),
-        Bloom::NATURAL,
+/// Bloom and the atmosphere are added by the quality preset.
+fn spawn_player(mut commands: Commands, camera_settings: Res<CameraSettings>) {
+    commands.spawn((
+        Player,
+        PlayerLook {
+            yaw: 0.0,
+            pitch: 0.0,
+        },
+        Camera3d::default(),
+        Projection::from(PerspectiveProjection {
+            fov: camera_settings.fov_radians(),
+            near: 0.01,
+            ..default()
+        }),
+        Exposure { ev100: BASE_EV100 },
+        Transform::from_xyz(0.0, 10.0, 0.0),
+        PlayerVelocity::default(),
+        HeadBob::default(),
+        DreamShake::default(),
+        CameraBoom::default(),
+        DreamSettings {
+            intensity: 0.0,
+            time: 0.0,
+            vignette: DREAM_VIGNETTE,
+            grain: DREAM_GRAIN,
+            swirl: 1.0,
+            ..default()
+        },
     ));
 }
 
@@ -130,22 +272,91 @@ fn toggle_cursor_grab(
     }
 }
 
-fn mouse_look(
-    mut motion: MessageReader<MouseMotion>,
-    mut query: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+fn spawn_look_prompt(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(60.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LookPrompt,
+                Text::new("Click to look around"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.0)),
+            ));
+        });
+}
+
+/// Fade the look prompt in while the cursor is free during play, and out once
+/// it is captured.
+fn update_look_prompt(
+    time: Res<Time<Real>>,
+    section: Res<State<Sections>>,
     cursor: Query<&CursorOptions>,
+    mut prompt: Query<&mut TextColor, With<LookPrompt>>,
 ) {
-    let Ok(cursor) = cursor.single() else {
+    let Ok(mut color) = prompt.single_mut() else {
         return;
     };
-    if cursor.grab_mode != CursorGrabMode::Locked {
-        return;
-    }
+    let playing = matches!(
+        **section,
+        Sections::Chase | Sections::Underworld | Sections::Stairs
+    );
+    let free = cursor
+        .single()
+        .is_ok_and(|cursor| cursor.grab_mode != CursorGrabMode::Locked);
+    let target = if playing && free { 1.0 } else { 0.0 };
+
+    let alpha = color.0.alpha();
+    let step = PROMPT_FADE_RATE * time.delta_secs();
+    let alpha = alpha + (target - alpha).clamp(-step, step);
+    color.0.set_alpha(alpha);
+}
 
-    let mut delta = Vec2::ZERO;
-    for ev in motion.read() {
-        delta += ev.delta;
-    }
+pub(crate) fn gather_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut motion: MessageReader<MouseMotion>,
+    cursor: Query<&CursorOptions>,
+    mut input: ResMut<PlayerInput>,
+    mut was_locked: Local<bool>,
+) {
+    let locked = cursor
+        .single()
+        .is_ok_and(|cursor| cursor.grab_mode == CursorGrabMode::Locked);
+    let just_locked = locked && !*was_locked;
+    *was_locked = locked;
+    // Motion from while the cursor was free, and the jump as it is captured,
+    // would otherwise yank the view on re-grab.
+    let look = if !locked || just_locked {
+        motion.clear();
+        Vec2::ZERO
+    } else {
+        motion.read().map(|ev| ev.delta).sum()
+    };
+    *input = PlayerInput {
+        forward: keyboard.pressed(KeyCode::KeyW),
+        back: keyboard.pressed(KeyCode::KeyS),
+        crouch: keyboard.any_pressed(CROUCH_KEYS),
+        look,
+    };
+}
+
+fn mouse_look(
+    input: Res<PlayerInput>,
+    mut query: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
+    camera: Res<CameraSettings>,
+) {
+    let delta = input.look;
     if delta == Vec2::ZERO {
         return;
     }
@@ -154,37 +365,161 @@ fn mouse_look(
         return;
     };
     look.yaw -= delta.x * MOUSE_SENSITIVITY;
-    look.pitch = (look.pitch - delta.y * MOUSE_SENSITIVITY).clamp(-MAX_PITCH, MAX_PITCH);
+    let max_pitch = camera.max_pitch();
+    let step = -delta.y * MOUSE_SENSITIVITY;
+    // Ease off the sensitivity through the soft zone when heading toward the
+    // limit; looking back toward level is never slowed. The clamp still holds
+    // as the last word.
+    let toward_limit = step * look.pitch > 0.0;
+    let ease = if toward_limit {
+        ((max_pitch - look.pitch.abs()) / PITCH_SOFT_ZONE).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    look.pitch = (look.pitch + step * ease).clamp(-max_pitch, max_pitch);
     transform.rotation = Quat::from_rotation_y(look.yaw) * Quat::from_rotation_x(look.pitch);
 }
 
 fn player_movement(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut query: Query<&mut Transform, With<Player>>,
+    input: Res<PlayerInput>,
+    mut query: Query<(&mut Transform, &mut PlayerVelocity), With<Player>>,
     time: Res<Time>,
     section: Res<State<Sections>>,
+    mut stationary: ResMut<Stationary>,
+    config: Res<PlayerConfig>,
+    mut crouch: ResMut<Crouch>,
 ) {
-    let Ok(mut transform) = query.single_mut() else {
+    let Ok((mut transform, mut velocity)) = query.single_mut() else {
         return;
     };
+    let dt = time.delta_secs();
+
+    let crouching = if input.crouch { 1.0 } else { 0.0 };
+    crouch.0 = crouch.0.move_towards(crouching, CROUCH_RATE * dt);
 
     let forward = *transform.forward();
     let forward_xz = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
 
     let mut movement = Vec3::ZERO;
-    if keyboard.pressed(KeyCode::KeyW) {
+    if input.forward {
         movement += forward_xz;
     }
-    if keyboard.pressed(KeyCode::KeyS) {
+    if input.back {
         movement -= forward_xz;
     }
 
     let move_speed = match **section {
         Sections::Chase => MOVE_SPEED,
         _ => MOVE_SPEED / 2.0,
+    } * 1.0_f32.lerp(config.crouch_speed_mul, crouch.0);
+
+    // Linear ease toward the target, so the player picks up speed and coasts
+    // to a stop. The target never exceeds the section's speed.
+    let target = movement * move_speed;
+    let rate = if movement == Vec3::ZERO {
+        WALK_DECEL
+    } else {
+        WALK_ACCEL
     };
+    velocity.0 = velocity.0.move_towards(target, rate * dt);
+    transform.translation += velocity.0 * dt;
 
-    transform.translation += movement * move_speed * time.delta_secs();
+    if movement == Vec3::ZERO {
+        stationary.0 += dt;
+    } else {
+        stationary.0 = 0.0;
+    }
+}
+
+fn remove_head_bob(mut query: Query<(&mut Transform, &mut HeadBob), With<Player>>) {
+    let Ok((mut transform, mut bob)) = query.single_mut() else {
+        return;
+    };
+    transform.translation -= bob.offset;
+    bob.offset = Vec3::ZERO;
+}
+
+pub(crate) fn apply_head_bob(
+    mut query: Query<(&mut Transform, &mut HeadBob), With<Player>>,
+    settings: Res<InputSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    section: Res<State<Sections>>,
+    photo: Res<PhotoMode>,
+    time: Res<Time>,
+) {
+    let Ok((mut transform, mut bob)) = query.single_mut() else {
+        return;
+    };
+
+    let walking_section = matches!(
+        **section,
+        Sections::Chase | Sections::Underworld | Sections::Stairs
+    );
+    if !settings.head_bob || accessibility.reduced_motion || !walking_section {
+        bob.amplitude = 0.0;
+        bob.last_base = None;
+        return;
+    }
+
+    let dt = time.delta_secs();
+    let base = transform.translation;
+    let moved = bob.last_base.map_or(0.0, |last| {
+        Vec2::new(base.x - last.x, base.z - last.z).length()
+    });
+    bob.last_base = Some(base);
+
+    // Ignore teleports and photo-mode flight; ease out toward centre instead.
+    let speed = if dt > 0.0 && !photo.active {
+        (moved / dt).min(MOVE_SPEED)
+    } else {
+        0.0
+    };
+    let target = speed / MOVE_SPEED;
+    bob.amplitude += (target - bob.amplitude) * (BOB_SMOOTHING * dt).min(1.0);
+    bob.phase = (bob.phase + speed * dt * BOB_FREQUENCY * std::f32::consts::TAU)
+        .rem_euclid(std::f32::consts::TAU);
+
+    let right = transform.right();
+    let right_xz = Vec3::new(right.x, 0.0, right.z).normalize_or_zero();
+    let vertical = (bob.phase * 2.0).sin() * BOB_VERTICAL;
+    let lateral = bob.phase.sin() * BOB_LATERAL;
+    bob.offset = (Vec3::Y * vertical + right_xz * lateral) * bob.amplitude;
+    transform.translation += bob.offset;
+}
+
+fn remove_dream_shake(mut query: Query<(&mut Transform, &mut DreamShake), With<Player>>) {
+    let Ok((mut transform, mut shake)) = query.single_mut() else {
+        return;
+    };
+    transform.rotation *= shake.offset.inverse();
+    shake.offset = Quat::IDENTITY;
+}
+
+pub(crate) fn apply_dream_shake(
+    mut query: Query<(&mut Transform, &mut DreamShake, &DreamSettings), With<Player>>,
+    accessibility: Res<AccessibilitySettings>,
+    section: Res<State<Sections>>,
+    photo: Res<PhotoMode>,
+    time: Res<Time>,
+) {
+    let Ok((mut transform, mut shake, dream)) = query.single_mut() else {
+        return;
+    };
+    let playing = matches!(
+        **section,
+        Sections::Chase | Sections::Underworld | Sections::Stairs
+    );
+    if accessibility.reduced_motion || !playing || photo.active || dream.intensity <= 0.0 {
+        return;
+    }
+
+    // Two incommensurate sines per axis read as noise without a noise source.
+    let t = time.elapsed_secs();
+    let yaw = (t * 7.3).sin() * 0.6 + (t * 13.1).sin() * 0.4;
+    let pitch = (t * 8.7).cos() * 0.6 + (t * 11.9).sin() * 0.4;
+    let amount = SHAKE_ANGLE * dream.intensity;
+    shake.offset = Quat::from_euler(EulerRot::YXZ, yaw * amount, pitch * amount, 0.0);
+    transform.rotation *= shake.offset;
 }
 
 const ARMS_6F_PATH: &str = "character/arms-6finger.gltf";
@@ -214,6 +549,7 @@ fn spawn_torch_arms(
     mut commands: Commands,
     player: Query<Entity, With<Player>>,
     assets: Res<ArmAssets>,
+    config: Res<PlayerConfig>,
 ) {
     let Ok(player_entity) = player.single() else {
         return;
@@ -223,7 +559,7 @@ fn spawn_torch_arms(
             .spawn((
                 PlayerArms,
                 SceneRoot(assets.scene.clone()),
-                Transform::from_xyz(0.0, -0.1 - EYE_HEIGHT, -0.19)
+                Transform::from_xyz(0.0, -0.1 - config.eye_height, -0.19)
                     .with_rotation(Quat::from_rotation_y(PI)),
             ))
             .observe(start_torch_animation);
@@ -255,36 +591,56 @@ fn start_torch_animation(
 
         // Spawn a point light at the candle's Empty node.
         if names.get(child).is_ok_and(|n| n.as_str() == "Empty") {
+            let light = PointLight {
+                color: Color::linear_rgb(1.0, 0.7, 0.3),
+                intensity: 50_000.0,
+                range: 120.0,
+                ..default()
+            };
             commands.entity(child).with_children(|parent| {
-                parent.spawn(PointLight {
-                    color: Color::linear_rgb(1.0, 0.7, 0.3),
-                    intensity: 50_000.0,
-                    range: 120.0,
-                    ..default()
-                });
+                parent.spawn((TorchFlicker::new(&light, CANDLE_FLICKER, 0.0), light));
             });
         }
     }
 }
 
 fn despawn_arms(mut commands: Commands, arms: Query<Entity, With<PlayerArms>>) {
-    if let Ok(entity) = arms.single() {
+    for entity in &arms {
         commands.entity(entity).despawn();
     }
 }
 
-fn reset_player(
-    mut query: Query<(&mut Transform, &mut PlayerLook, &mut DreamSettings), With<Player>>,
+/// Brightness is a linear light multiplier, so it shifts exposure by its log2
+/// in stops.
+fn apply_brightness(display: Res<DisplaySettings>, mut camera: Query<&mut Exposure, With<Player>>) {
+    let Ok(mut exposure) = camera.single_mut() else {
+        return;
+    };
+    exposure.ev100 = BASE_EV100 - display.brightness.max(0.01).log2();
+}
+
+fn apply_fov(settings: Res<CameraSettings>, mut camera: Query<&mut Projection, With<Player>>) {
+    let Ok(mut projection) = camera.single_mut() else {
+        return;
+    };
+    if let Projection::Perspective(perspective) = projection.as_mut() {
+        perspective.fov = settings.fov_radians();
+    }
+}
+
+pub(crate) fn reset_player(
+    mut query: Query<(&mut Transform, &mut PlayerLook, &mut PlayerVelocity), With<Player>>,
+    mut crouch: ResMut<Crouch>,
 ) {
-    let Ok((mut transform, mut look, mut dream)) = query.single_mut() else {
+    crouch.0 = 0.0;
+    let Ok((mut transform, mut look, mut velocity)) = query.single_mut() else {
         return;
     };
     transform.translation = Vec3::new(0.0, 10.0, 0.0);
+    velocity.0 = Vec3::ZERO;
     look.yaw = 0.0;
     look.pitch = 0.0;
     transform.rotation = Quat::IDENTITY;
-    dream.intensity = 0.0;
-    dream.time = 0.0;
 }
 
 fn spawn_chase_light(mut commands: Commands) {
@@ -0,0 +1,101 @@
+// Contextual hint at the bottom of the screen. Sections raise a prompt while
+// its condition holds and clear it otherwise; this owns the text and the fade.
+
+use bevy::prelude::*;
+
+use crate::sections::Sections;
+
+pub struct PromptPlugin;
+
+impl Plugin for PromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Prompt>()
+            .add_systems(Startup, spawn_prompt)
+            .add_systems(
+                Update,
+                (
+                    clear_prompt.run_if(state_changed::<Sections>),
+                    update_prompt,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Alpha per second at which the prompt fades in and out.
+const PROMPT_FADE_RATE: f32 = 1.5;
+const PROMPT_ALPHA: f32 = 0.8;
+
+/// The hint to show, if any. Set every frame the condition holds; writes of the
+/// same text are cheap and leave the fade alone.
+#[derive(Resource, Default)]
+pub struct Prompt(Option<&'static str>);
+
+impl Prompt {
+    pub fn show(&mut self, text: &'static str) {
+        self.0 = Some(text);
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+}
+
+#[derive(Component)]
+struct PromptText;
+
+fn spawn_prompt(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                bottom: Val::Percent(20.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                PromptText,
+                Text::new(""),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.0)),
+            ));
+        });
+}
+
+/// A prompt never carries over into the next section.
+fn clear_prompt(mut prompt: ResMut<Prompt>) {
+    prompt.clear();
+}
+
+/// Fade toward the requested prompt. A different text waits for the old one to
+/// fade out before it is swapped in, so it never changes while visible.
+fn update_prompt(
+    time: Res<Time>,
+    prompt: Res<Prompt>,
+    mut text: Query<(&mut Text, &mut TextColor), With<PromptText>>,
+) {
+    let Ok((mut text, mut color)) = text.single_mut() else {
+        return;
+    };
+    let alpha = color.0.alpha();
+    if let Some(wanted) = prompt.0.filter(|wanted| alpha == 0.0 && text.0 != *wanted) {
+        text.0 = wanted.to_string();
+    }
+
+    let target = if prompt.0 == Some(text.0.as_str()) {
+        PROMPT_ALPHA
+    } else {
+        0.0
+    };
+    let step = PROMPT_FADE_RATE * time.delta_secs();
+    color
+        .0
+        .set_alpha(alpha + (target - alpha).clamp(-step, step));
+}
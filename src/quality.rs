@@ -0,0 +1,85 @@
+// Quality preset: fans one setting out to the resources and components it
+// covers whenever it changes.
+
+use bevy::prelude::*;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::{
+    light::AtmosphereEnvironmentMapLight,
+    pbr::{Atmosphere, AtmosphereSettings, ScatteringMedium},
+    post_process::bloom::Bloom,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::player::Player;
+use crate::settings::{QualityPreset, RenderDistance};
+use crate::terrain::TerrainConfig;
+
+pub struct QualityPlugin;
+
+impl Plugin for QualityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                apply_quality.run_if(resource_changed::<QualityPreset>),
+                apply_sun_shadows,
+            ),
+        );
+    }
+}
+
+/// Terrain settings only reach chunks spawned afterwards, which is fine since
+/// the preset is only changed from the menu, where there is no terrain.
+fn apply_quality(
+    preset: Res<QualityPreset>,
+    mut render_distance: ResMut<RenderDistance>,
+    mut terrain: ResMut<TerrainConfig>,
+    #[cfg(not(target_arch = "wasm32"))] mut commands: Commands,
+    #[cfg(not(target_arch = "wasm32"))] camera: Query<Entity, With<Player>>,
+    #[cfg(not(target_arch = "wasm32"))] mut scattering_mediums: ResMut<Assets<ScatteringMedium>>,
+    #[cfg(not(target_arch = "wasm32"))] mut medium: Local<Option<Handle<ScatteringMedium>>>,
+) {
+    render_distance.set_if_neq(preset.render_distance());
+    terrain.chunk_resolution = preset.chunk_resolution();
+    terrain.object_density = preset.object_density();
+    terrain.max_objects = preset.max_objects();
+
+    // Bloom and the atmosphere are native only. Nothing in the look depends
+    // on bloom, and without the atmosphere the sky falls back to the clear
+    // colour.
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(camera) = camera.single() {
+        let mut camera = commands.entity(camera);
+        if preset.bloom() {
+            camera.insert(Bloom::NATURAL);
+        } else {
+            camera.remove::<Bloom>();
+        }
+        if preset.atmosphere() {
+            let medium = medium
+                .get_or_insert_with(|| scattering_mediums.add(ScatteringMedium::default()))
+                .clone();
+            camera.insert((
+                Atmosphere::earthlike(medium),
+                AtmosphereSettings::default(),
+                AtmosphereEnvironmentMapLight::default(),
+            ));
+        } else {
+            camera.remove::<(
+                Atmosphere,
+                AtmosphereSettings,
+                AtmosphereEnvironmentMapLight,
+            )>();
+        }
+    }
+}
+
+/// Directional lights are spawned per section, so keep catching new ones.
+fn apply_sun_shadows(preset: Res<QualityPreset>, mut lights: Query<&mut DirectionalLight>) {
+    let enabled = preset.sun_shadows();
+    for mut light in &mut lights {
+        if light.shadows_enabled != enabled {
+            light.shadows_enabled = enabled;
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// Input recording and playback. Every run's movement and look input is kept
+// frame by frame with the frame's length; feeding it back, with time stepped
+// by the same lengths, replays the run for a bug report or as a shared ghost.
+// Saved next to the executable on native builds, in memory on the web.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::dream::DevOverlay;
+use crate::player::{PlayerInput, ReadInput, gather_input};
+use crate::sections::Sections;
+use crate::terrain::TerrainSeed;
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecorder>()
+            .init_resource::<InputPlayback>()
+            .add_systems(OnEnter(Sections::Chase), start_run)
+            .add_systems(
+                Update,
+                (play_back_input, record_input)
+                    .chain()
+                    .in_set(ReadInput)
+                    .after(gather_input)
+                    .run_if(
+                        in_state(Sections::Chase)
+                            .or(in_state(Sections::Underworld))
+                            .or(in_state(Sections::Stairs)),
+                    ),
+            )
+            .add_systems(
+                Update,
+                (save_replay, start_playback).run_if(|overlay: Res<DevOverlay>| overlay.0),
+            )
+            .add_systems(Last, step_time);
+    }
+}
+
+/// Both only while the dev overlay is up.
+#[cfg(not(target_arch = "wasm32"))]
+const SAVE_REPLAY_KEY: KeyCode = KeyCode::F6;
+const PLAY_REPLAY_KEY: KeyCode = KeyCode::F7;
+
+#[cfg(not(target_arch = "wasm32"))]
+const REPLAY_FILE: &str = "replay.cfg";
+
+/// One frame of play: how long it lasted and what the player did.
+#[derive(Clone, Copy, Debug)]
+struct InputFrame {
+    dt: f32,
+    input: PlayerInput,
+}
+
+impl InputFrame {
+    /// `<dt> <forward><back><crouch> <look x> <look y>`, flags as 0 or 1.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn to_line(self) -> String {
+        let flag = |on: bool| if on { '1' } else { '0' };
+        format!(
+            "{} {}{}{} {} {}",
+            self.dt,
+            flag(self.input.forward),
+            flag(self.input.back),
+            flag(self.input.crouch),
+            self.input.look.x,
+            self.input.look.y
+        )
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn from_line(line: &str) -> Option<InputFrame> {
+        let mut fields = line.split_whitespace();
+        let dt = fields.next()?.parse().ok()?;
+        let flags = fields.next()?.as_bytes();
+        let [forward, back, crouch] = flags else {
+            return None;
+        };
+        Some(InputFrame {
+            dt,
+            input: PlayerInput {
+                forward: *forward == b'1',
+                back: *back == b'1',
+                crouch: *crouch == b'1',
+                look: Vec2::new(fields.next()?.parse().ok()?, fields.next()?.parse().ok()?),
+            },
+        })
+    }
+}
+
+/// The current, or last, run's input, with the seed it was played on.
+#[derive(Resource, Default)]
+pub struct InputRecorder {
+    seed: u32,
+    frames: Vec<InputFrame>,
+}
+
+/// A run being fed back in place of the devices. `next` indexes the frame
+/// the coming Update will use.
+#[derive(Resource, Default)]
+pub struct InputPlayback {
+    frames: Vec<InputFrame>,
+    next: usize,
+    active: bool,
+}
+
+impl InputPlayback {
+    fn upcoming(&self) -> Option<&InputFrame> {
+        self.frames.get(self.next).filter(|_| self.active)
+    }
+}
+
+/// A new run starts a new recording. A playback that's queued carries on.
+fn start_run(seed: Res<TerrainSeed>, mut recorder: ResMut<InputRecorder>) {
+    recorder.seed = seed.0;
+    recorder.frames.clear();
+}
+
+fn play_back_input(mut playback: ResMut<InputPlayback>, mut input: ResMut<PlayerInput>) {
+    let Some(frame) = playback.upcoming().copied() else {
+        return;
+    };
+    *input = frame.input;
+    playback.next += 1;
+    if playback.next == playback.frames.len() {
+        playback.active = false;
+        info!("Replay finished");
+    }
+}
+
+fn record_input(
+    time: Res<Time<Real>>,
+    input: Res<PlayerInput>,
+    mut recorder: ResMut<InputRecorder>,
+) {
+    recorder.frames.push(InputFrame {
+        dt: time.delta_secs(),
+        input: *input,
+    });
+}
+
+/// While a replay runs, each frame lasts exactly as long as the recorded one,
+/// so everything integrated over time lands where it did.
+fn step_time(playback: Res<InputPlayback>, mut strategy: ResMut<TimeUpdateStrategy>) {
+    *strategy = match playback.upcoming() {
+        Some(frame) => TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(frame.dt)),
+        None => TimeUpdateStrategy::Automatic,
+    };
+}
+
+/// Restart the chase and feed the saved run back, or the last run if nothing
+/// is saved. The seed has to match for the same world to come out.
+fn start_playback(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    seed: Res<TerrainSeed>,
+    recorder: Res<InputRecorder>,
+    mut playback: ResMut<InputPlayback>,
+    mut next_state: ResMut<NextState<Sections>>,
+) {
+    if !keyboard.just_pressed(PLAY_REPLAY_KEY) {
+        return;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let loaded = load_replay();
+    #[cfg(target_arch = "wasm32")]
+    let loaded = None;
+    let (replay_seed, frames) = loaded.unwrap_or((recorder.seed, recorder.frames.clone()));
+    if frames.is_empty() {
+        info!("No replay to play");
+        return;
+    }
+    if replay_seed != seed.0 {
+        warn!(
+            "Replay was recorded on seed {replay_seed} but this world is seed {}; it will drift",
+            seed.0
+        );
+    }
+    *playback = InputPlayback {
+        frames,
+        next: 0,
+        active: true,
+    };
+    next_state.set(Sections::Chase);
+}
+
+/// `seed=<n>` then one frame per line.
+#[cfg(not(target_arch = "wasm32"))]
+fn save_replay(keyboard: Res<ButtonInput<KeyCode>>, recorder: Res<InputRecorder>) {
+    if !keyboard.just_pressed(SAVE_REPLAY_KEY) {
+        return;
+    }
+    let mut contents = format!("seed={}\n", recorder.seed);
+    for frame in &recorder.frames {
+        contents.push_str(&frame.to_line());
+        contents.push('\n');
+    }
+    match std::fs::write(replay_path(), contents) {
+        Ok(()) => info!("Saved {} frames of input", recorder.frames.len()),
+        Err(error) => warn!("Couldn't save the replay: {error}"),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_replay() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn replay_path() -> std::path::PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(std::path::PathBuf::from))
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join(REPLAY_FILE)
+}
+
+/// A frame that doesn't parse ends the replay there, since everything after
+/// it would be out of step anyway.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_replay() -> Option<(u32, Vec<InputFrame>)> {
+    let contents = std::fs::read_to_string(replay_path()).ok()?;
+    let mut lines = contents.lines();
+    let seed = lines.next()?.strip_prefix("seed=")?.trim().parse().ok()?;
+    let frames = lines.map_while(InputFrame::from_line).collect();
+    Some((seed, frames))
+}
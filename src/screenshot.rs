@@ -0,0 +1,75 @@
+// Save screenshots to disk (or download them on the web) on a key press.
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+
+pub struct ScreenshotPlugin;
+
+impl Plugin for ScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenshotConfig>()
+            .add_systems(Update, capture_screenshot);
+    }
+}
+
+pub(crate) const SCREENSHOT_KEY: KeyCode = KeyCode::F12;
+
+/// Where screenshots are written and how they are named.
+#[derive(Resource)]
+pub struct ScreenshotConfig {
+    /// Output directory. Ignored on the web, where the file is downloaded.
+    pub directory: PathBuf,
+    /// File name prefix, followed by a timestamp and `.png`.
+    pub prefix: String,
+}
+
+impl Default for ScreenshotConfig {
+    fn default() -> Self {
+        // Next to the executable, falling back to the working directory.
+        let directory = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from("."));
+        Self {
+            directory,
+            prefix: "eurydice".into(),
+        }
+    }
+}
+
+fn capture_screenshot(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<ScreenshotConfig>,
+    time: Res<Time<Real>>,
+) {
+    if !keyboard.just_pressed(SCREENSHOT_KEY) {
+        return;
+    }
+
+    let file_name = format!("{}-{}.png", config.prefix, timestamp(&time));
+    #[cfg(not(target_arch = "wasm32"))]
+    let path = config.directory.join(file_name);
+    #[cfg(target_arch = "wasm32")]
+    let path = PathBuf::from(file_name);
+
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path));
+}
+
+/// Milliseconds since the Unix epoch on native; the wasm clock has no wall time,
+/// so fall back to milliseconds since startup.
+#[cfg(not(target_arch = "wasm32"))]
+fn timestamp(_time: &Time<Real>) -> String {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn timestamp(time: &Time<Real>) -> String {
+    time.elapsed().as_millis().to_string()
+}
@@ -12,8 +12,33 @@ pub enum Sections {
 }
 
 /// Flags that persist across section transitions to drive plot branching.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PlotFlags {
     pub player_looked_behind: bool,
     pub chevron_count: u32,
+    /// Highest dream intensity reached during the chase.
+    pub peak_dream_intensity: f32,
+    /// Terrain rotations over the whole chase.
+    pub total_rotations: u32,
+}
+
+/// A windowless App sitting in `section`, for testing the flow between
+/// sections. Tests add the systems they exercise; time steps a tenth of a
+/// second per update.
+#[cfg(test)]
+pub fn headless_app(section: Sections) -> App {
+    use bevy::input::InputPlugin;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, StatesPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_state(section)
+        .init_resource::<PlotFlags>();
+    app
 }
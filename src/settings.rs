@@ -0,0 +1,363 @@
+// Player-facing settings chosen from the menu.
+use bevy::prelude::*;
+
+/// Scales the chase pacing. Read every frame, so it can change mid-run.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Difficulty {
+    /// Multiplier on the base dream intensity ramp.
+    pub dream_rate_mul: f32,
+    /// Multiplier on the NPC sprint speed.
+    pub npc_speed_mul: f32,
+    /// Seconds into the chase before the dream starts to build, so the player
+    /// can get their bearings and find the NPC.
+    pub dream_onset: f32,
+}
+
+impl Difficulty {
+    pub const EASY: Difficulty = Difficulty {
+        dream_rate_mul: 0.6,
+        npc_speed_mul: 0.75,
+        dream_onset: 20.0,
+    };
+    pub const NORMAL: Difficulty = Difficulty {
+        dream_rate_mul: 1.0,
+        npc_speed_mul: 1.0,
+        dream_onset: 12.0,
+    };
+    pub const HARD: Difficulty = Difficulty {
+        dream_rate_mul: 1.6,
+        npc_speed_mul: 1.2,
+        dream_onset: 6.0,
+    };
+
+    const PRESETS: [(&'static str, Difficulty); 3] = [
+        ("Easy", Difficulty::EASY),
+        ("Normal", Difficulty::NORMAL),
+        ("Hard", Difficulty::HARD),
+    ];
+
+    /// Name of the matching preset, or "Custom" for hand-tuned values.
+    pub fn label(&self) -> &'static str {
+        Self::PRESETS
+            .iter()
+            .find(|(_, preset)| preset == self)
+            .map_or("Custom", |(name, _)| name)
+    }
+
+    /// Cycle to the next preset, wrapping from Hard back to Easy.
+    pub fn next(&self) -> Difficulty {
+        let index = Self::PRESETS
+            .iter()
+            .position(|(_, preset)| preset == self)
+            .map_or(0, |i| (i + 1) % Self::PRESETS.len());
+        Self::PRESETS[index].1
+    }
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Difficulty::NORMAL
+    }
+}
+
+/// Camera and control preferences.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct InputSettings {
+    /// Bob the camera while walking. Off for motion-sensitive players.
+    pub head_bob: bool,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self { head_bob: true }
+    }
+}
+
+/// Player camera preferences.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct CameraSettings {
+    /// Vertical field of view in degrees.
+    pub fov: f32,
+    /// How far the view tilts up or down from level, in radians.
+    pub max_pitch: f32,
+}
+
+impl CameraSettings {
+    pub const MIN_FOV: f32 = 50.0;
+    pub const MAX_FOV: f32 = 110.0;
+    const FOV_STEPS: [f32; 4] = [60.0, 72.0, 85.0, 100.0];
+    /// Short of straight up or down, where yaw stops making sense.
+    const PITCH_LIMIT: f32 = 1.5;
+
+    /// Look limit in radians, kept within what the camera can handle.
+    pub fn max_pitch(&self) -> f32 {
+        self.max_pitch.clamp(0.1, Self::PITCH_LIMIT)
+    }
+
+    /// Field of view in radians, clamped to the supported range.
+    pub fn fov_radians(&self) -> f32 {
+        self.fov.clamp(Self::MIN_FOV, Self::MAX_FOV).to_radians()
+    }
+
+    /// Cycle to the next FOV step, wrapping back to the narrowest.
+    pub fn next_fov(&self) -> f32 {
+        let index = Self::FOV_STEPS
+            .iter()
+            .position(|step| *step == self.fov)
+            .map_or(1, |i| (i + 1) % Self::FOV_STEPS.len());
+        Self::FOV_STEPS[index]
+    }
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            fov: 72.0,
+            max_pitch: 1.3,
+        }
+    }
+}
+
+/// Display adjustments for screens the dark sections weren't tuned on.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct DisplaySettings {
+    /// Linear multiplier on scene exposure. 1.0 is the tuned look; 2.0 lets in
+    /// twice the light.
+    pub brightness: f32,
+    /// Borderless fullscreen on the current monitor instead of a window.
+    pub fullscreen: bool,
+    /// How fast frames are drawn. The browser paces the web build itself.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub frame_limit: FrameLimit,
+}
+
+impl DisplaySettings {
+    const BRIGHTNESS_STEPS: [f32; 5] = [0.75, 1.0, 1.5, 2.0, 3.0];
+
+    /// Cycle to the next brightness step, wrapping back to the dimmest.
+    pub fn next_brightness(&self) -> f32 {
+        let index = Self::BRIGHTNESS_STEPS
+            .iter()
+            .position(|step| *step == self.brightness)
+            .map_or(1, |i| (i + 1) % Self::BRIGHTNESS_STEPS.len());
+        Self::BRIGHTNESS_STEPS[index]
+    }
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            brightness: 1.0,
+            fullscreen: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            frame_limit: FrameLimit::default(),
+        }
+    }
+}
+
+/// Cap on the frame rate, so the menu and the slow sections don't spin the
+/// GPU flat out for frames nobody sees.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FrameLimit {
+    /// Wait for the display's refresh.
+    #[default]
+    VSync,
+    Fps30,
+    Fps60,
+    Fps120,
+    Unlimited,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FrameLimit {
+    const ALL: [FrameLimit; 5] = [
+        FrameLimit::VSync,
+        FrameLimit::Fps30,
+        FrameLimit::Fps60,
+        FrameLimit::Fps120,
+        FrameLimit::Unlimited,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FrameLimit::VSync => "VSync",
+            FrameLimit::Fps30 => "30",
+            FrameLimit::Fps60 => "60",
+            FrameLimit::Fps120 => "120",
+            FrameLimit::Unlimited => "Off",
+        }
+    }
+
+    /// Cycle to the next limit, wrapping from Unlimited back to VSync.
+    pub fn next(&self) -> FrameLimit {
+        let index = Self::ALL
+            .iter()
+            .position(|limit| limit == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Frames per second to hold to, when the limit is a fixed rate.
+    pub fn fps(&self) -> Option<f32> {
+        match self {
+            FrameLimit::Fps30 => Some(30.0),
+            FrameLimit::Fps60 => Some(60.0),
+            FrameLimit::Fps120 => Some(120.0),
+            FrameLimit::VSync | FrameLimit::Unlimited => None,
+        }
+    }
+
+    /// Name written to the settings file.
+    pub fn key(&self) -> &'static str {
+        match self {
+            FrameLimit::VSync => "vsync",
+            FrameLimit::Fps30 => "30",
+            FrameLimit::Fps60 => "60",
+            FrameLimit::Fps120 => "120",
+            FrameLimit::Unlimited => "unlimited",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<FrameLimit> {
+        Self::ALL.into_iter().find(|limit| limit.key() == key)
+    }
+}
+
+/// How many chunks of terrain the chase keeps around the player. Set by the
+/// quality preset.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderDistance {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl RenderDistance {
+    /// Chunk radius handed to the terrain.
+    pub fn radius(&self) -> i32 {
+        match self {
+            RenderDistance::Low => 8,
+            RenderDistance::Medium => 12,
+            RenderDistance::High => 16,
+        }
+    }
+}
+
+/// One dial for the rendering cost, setting coherent values across terrain,
+/// post-processing and lighting.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityPreset {
+    Low,
+    Medium,
+    High,
+}
+
+impl QualityPreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            QualityPreset::Low => "Low",
+            QualityPreset::Medium => "Medium",
+            QualityPreset::High => "High",
+        }
+    }
+
+    /// Cycle to the next preset, wrapping from High back to Low.
+    pub fn next(&self) -> QualityPreset {
+        match self {
+            QualityPreset::Low => QualityPreset::Medium,
+            QualityPreset::Medium => QualityPreset::High,
+            QualityPreset::High => QualityPreset::Low,
+        }
+    }
+
+    pub fn render_distance(&self) -> RenderDistance {
+        match self {
+            QualityPreset::Low => RenderDistance::Low,
+            QualityPreset::Medium => RenderDistance::Medium,
+            QualityPreset::High => RenderDistance::High,
+        }
+    }
+
+    /// Vertices along each edge of a terrain chunk.
+    pub fn chunk_resolution(&self) -> usize {
+        match self {
+            QualityPreset::Low => 4,
+            QualityPreset::Medium => 5,
+            QualityPreset::High => 7,
+        }
+    }
+
+    /// Fraction of candidate object placements that are kept.
+    pub fn object_density(&self) -> f32 {
+        match self {
+            QualityPreset::Low => 0.5,
+            QualityPreset::Medium => 0.8,
+            QualityPreset::High => 1.0,
+        }
+    }
+
+    /// Cap on terrain objects alive at once.
+    pub fn max_objects(&self) -> usize {
+        match self {
+            QualityPreset::Low => 1500,
+            QualityPreset::Medium => 3000,
+            QualityPreset::High => 6000,
+        }
+    }
+
+    pub fn bloom(&self) -> bool {
+        !matches!(self, QualityPreset::Low)
+    }
+
+    /// Atmospheric sky and its environment lighting, on native builds.
+    pub fn atmosphere(&self) -> bool {
+        !matches!(self, QualityPreset::Low)
+    }
+
+    /// Shadows from the chase sun.
+    pub fn sun_shadows(&self) -> bool {
+        matches!(self, QualityPreset::High)
+    }
+}
+
+impl Default for QualityPreset {
+    /// The web build starts a step down; WebGL2 struggles with the full set.
+    fn default() -> Self {
+        if cfg!(target_arch = "wasm32") {
+            QualityPreset::Medium
+        } else {
+            QualityPreset::High
+        }
+    }
+}
+
+/// Turn the chase camera toward the NPC on its own, so the player only has to
+/// walk. Mouse look still works and takes over for a moment when used.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct AutoCamera(pub bool);
+
+/// Pull the camera back behind a visible body instead of looking from the eye,
+/// for players who find first person uncomfortable.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ThirdPerson(pub bool);
+
+/// Options that make the game readable for more players.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct AccessibilitySettings {
+    /// Outline the NPC chevron and signal danger by shape, fill and pulse
+    /// rather than by colour alone.
+    pub high_contrast_chevron: bool,
+    /// Cut motion that can cause sickness: no chevron shake, head-bob or
+    /// camera shake, and a much weaker dream swirl.
+    pub reduced_motion: bool,
+    /// Never rumble the gamepad.
+    pub no_haptics: bool,
+    /// Let the chase carry on when the player abandons the NPC, instead of
+    /// restarting it.
+    pub no_soft_fail: bool,
+    /// Keep the NPC whole as the dream deepens: no draining colour, glow or
+    /// flicker.
+    pub no_npc_corruption: bool,
+}
@@ -0,0 +1,77 @@
+// Gradient sky dome for the chase on the web build, where the atmosphere
+// isn't available and the sky would otherwise be a flat fill.
+
+use bevy::mesh::VertexAttributeValues;
+use bevy::prelude::*;
+
+use crate::player::Player;
+use crate::sections::Sections;
+
+pub struct SkyPlugin;
+
+impl Plugin for SkyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Sections::Chase), spawn_sky_dome)
+            .add_systems(
+                PostUpdate,
+                follow_player
+                    .run_if(in_state(Sections::Chase))
+                    .before(TransformSystems::Propagate),
+            );
+    }
+}
+
+/// Well past the furthest chunk at the highest render radius, inside the
+/// camera's far plane.
+const SKY_RADIUS: f32 = 400.0;
+/// Pale haze where the sky meets the terrain.
+const SKY_HORIZON: Color = Color::linear_rgb(0.75, 0.88, 0.95);
+const SKY_ZENITH: Color = Color::linear_rgb(0.3, 0.55, 0.85);
+
+#[derive(Component)]
+struct SkyDome;
+
+fn spawn_sky_dome(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mut mesh = Sphere::new(SKY_RADIUS).mesh().uv(32, 16);
+    let colors: Vec<[f32; 4]> = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => positions
+            .iter()
+            .map(|p| {
+                // Quick falloff so most of the dome is blue and only a band
+                // near the horizon is hazy. Below the horizon stays haze.
+                let t = (p[1] / SKY_RADIUS).clamp(0.0, 1.0).sqrt();
+                SKY_HORIZON.mix(&SKY_ZENITH, t).to_linear().to_f32_array()
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+
+    commands.spawn((
+        SkyDome,
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            unlit: true,
+            fog: false,
+            // Seen from inside.
+            cull_mode: None,
+            ..default()
+        })),
+        Transform::default(),
+        DespawnOnExit(Sections::Chase),
+    ));
+}
+
+fn follow_player(
+    player: Query<&Transform, (With<Player>, Without<SkyDome>)>,
+    mut dome: Query<&mut Transform, With<SkyDome>>,
+) {
+    let (Ok(player), Ok(mut dome)) = (player.single(), dome.single_mut()) else {
+        return;
+    };
+    dome.translation = player.translation;
+}
@@ -0,0 +1,128 @@
+// Optional run timer with a split at the start of each section.
+
+use bevy::prelude::*;
+
+use crate::sections::Sections;
+
+pub struct SpeedrunPlugin;
+
+impl Plugin for SpeedrunPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunTimer>()
+            .add_systems(Startup, spawn_timer_hud)
+            .add_systems(OnEnter(Sections::Chase), start_run)
+            .add_systems(OnEnter(Sections::Underworld), split)
+            .add_systems(OnEnter(Sections::Stairs), split)
+            .add_systems(OnEnter(Sections::Awaken), split)
+            .add_systems(OnEnter(Sections::Menu), finish_run)
+            .add_systems(Update, (tick_run_timer, toggle_timer_hud, update_timer_hud));
+    }
+}
+
+pub(crate) const TIMER_KEY: KeyCode = KeyCode::KeyT;
+
+/// Time since the chase began, with the time each later section was reached.
+#[derive(Resource, Default)]
+pub struct RunTimer {
+    pub total: f32,
+    pub splits: Vec<(Sections, f32)>,
+    running: bool,
+}
+
+/// Whether the timer is drawn. Off until the player asks for it.
+#[derive(Component)]
+struct TimerHud {
+    shown: bool,
+}
+
+#[derive(Component)]
+struct TimerText;
+
+fn spawn_timer_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            TimerHud { shown: false },
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(16.0),
+                top: Val::Px(16.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TimerText,
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+            ));
+        });
+}
+
+/// A fresh run starts whenever the chase does.
+fn start_run(mut timer: ResMut<RunTimer>) {
+    *timer = RunTimer {
+        total: 0.0,
+        splits: vec![(Sections::Chase, 0.0)],
+        running: true,
+    };
+}
+
+fn split(mut timer: ResMut<RunTimer>, section: Res<State<Sections>>) {
+    if timer.running {
+        let total = timer.total;
+        timer.splits.push((**section, total));
+    }
+}
+
+fn finish_run(mut timer: ResMut<RunTimer>) {
+    timer.running = false;
+}
+
+fn tick_run_timer(time: Res<Time>, mut timer: ResMut<RunTimer>) {
+    if timer.running {
+        timer.total += time.delta_secs();
+    }
+}
+
+fn toggle_timer_hud(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut hud: Query<(&mut TimerHud, &mut Visibility)>,
+) {
+    if !keyboard.just_pressed(TIMER_KEY) {
+        return;
+    }
+    let Ok((mut hud, mut visibility)) = hud.single_mut() else {
+        return;
+    };
+    hud.shown = !hud.shown;
+    *visibility = if hud.shown {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+}
+
+fn update_timer_hud(timer: Res<RunTimer>, mut text: Query<&mut Text, With<TimerText>>) {
+    let Ok(mut text) = text.single_mut() else {
+        return;
+    };
+    let mut lines = format_time(timer.total);
+    for (section, at) in &timer.splits {
+        lines.push_str(&format!("\n{section:?} {}", format_time(*at)));
+    }
+    if text.0 != lines {
+        text.0 = lines;
+    }
+}
+
+/// `m:ss.cc`
+pub fn format_time(seconds: f32) -> String {
+    let minutes = (seconds / 60.0).floor() as u32;
+    format!("{minutes}:{:05.2}", seconds - minutes as f32 * 60.0)
+}
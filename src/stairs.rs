@@ -1,10 +1,19 @@
 // Stairs section: ascending corridor of finger-bone steps in darkness.
 
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
+use crate::chevron::point_chevron_at;
+use crate::flicker::TorchFlicker;
+use crate::haptics::{HapticCue, Haptics};
 use crate::npc::NpcChevron;
-use crate::player::{Player, PlayerLook};
+use crate::photo::photo_mode_inactive;
+use crate::player::{EyeHeight, Player, PlayerConfig, PlayerLook, Stationary};
+use crate::prompt::Prompt;
 use crate::sections::{PlotFlags, Sections};
+use crate::terrain::TerrainSeed;
+use crate::transition::AmbientFade;
 
 pub struct StairsPlugin;
 
@@ -19,30 +28,50 @@ impl Plugin for StairsPlugin {
                     stairs_chevron,
                     stairs_look_check,
                     stairs_exit,
+                    climb_rumble,
                 )
                     .chain()
-                    .run_if(in_state(Sections::Stairs)),
+                    .run_if(in_state(Sections::Stairs))
+                    .run_if(photo_mode_inactive),
             );
     }
 }
 
-const EYE_HEIGHT: f32 = 1.5;
 const CORRIDOR_HALF_WIDTH: f32 = 3.0;
 const CLAMP_MARGIN: f32 = 0.5;
 
 const STEP_HEIGHT: f32 = 0.15;
 const STEP_DEPTH: f32 = 1.0;
 const NUM_STEPS: usize = 80;
+/// Player z past which the climb is over.
+const EXIT_Z: f32 = -((NUM_STEPS - 2) as f32 * STEP_DEPTH);
 
 const FINGER_PATH: &str = "character/finger.gltf";
 /// Scale finger model down and widen to fit the corridor.
 const FINGER_SCALE: f32 = 1.0;
 const FINGER_X_SCALE: f32 = 1.0;
 
+// Visual-only jitter per step. Collision stays on the regular grid.
+/// Max yaw either way, in radians.
+const STEP_YAW_JITTER: f32 = 0.15;
+/// Max fractional change in scale.
+const STEP_SCALE_JITTER: f32 = 0.08;
+/// Max sideways offset, well inside the corridor half-width.
+const STEP_LATERAL_JITTER: f32 = 0.4;
+
 /// Yaw delta (radians) from initial direction to count as "looked behind".
 const LOOK_BEHIND_THRESHOLD: f32 = 2.6;
-
-const CHEVRON_MARGIN: f32 = 40.0;
+/// Seconds standing still on the stairs before the player is urged upward.
+const CLIMB_PROMPT_DELAY: f32 = 4.0;
+/// A low, steady rumble while the player climbs.
+const CLIMB_RUMBLE: HapticCue = HapticCue {
+    strong: 0.25,
+    weak: 0.0,
+    duration: 0.0,
+};
+
+/// The light at the top of the stairs wavers only faintly.
+const TOP_LIGHT_FLICKER: f32 = 0.12;
 
 #[derive(Resource)]
 struct StairsState {
@@ -55,28 +84,30 @@ struct StairStep;
 fn setup_stairs(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    seed: Res<TerrainSeed>,
+    player_config: Res<PlayerConfig>,
+    mut ambient: ResMut<AmbientFade>,
     mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
 ) {
-    commands.insert_resource(GlobalAmbientLight {
-        color: Color::srgb(0.3, 0.25, 0.35),
-        brightness: 3.0,
-        affects_lightmapped_meshes: false,
-    });
+    ambient.fade_to(Color::srgb(0.3, 0.25, 0.35), 3.0);
 
     let finger_scene: Handle<Scene> =
         asset_server.load(GltfAssetLabel::Scene(0).from_asset(FINGER_PATH));
 
+    // Same seed, same staircase.
+    let mut rng = StdRng::seed_from_u64(seed.0 as u64);
     for i in 0..NUM_STEPS {
         let z = -(i as f32 * STEP_DEPTH);
         let y = i as f32 * STEP_HEIGHT;
+        let x = rng.random_range(-STEP_LATERAL_JITTER..=STEP_LATERAL_JITTER);
+        let yaw = rng.random_range(-STEP_YAW_JITTER..=STEP_YAW_JITTER);
+        let scale = 1.0 + rng.random_range(-STEP_SCALE_JITTER..=STEP_SCALE_JITTER);
         commands.spawn((
             StairStep,
             SceneRoot(finger_scene.clone()),
-            Transform::from_xyz(0.0, y, z).with_scale(Vec3::new(
-                FINGER_X_SCALE,
-                FINGER_SCALE,
-                FINGER_SCALE,
-            )),
+            Transform::from_xyz(x, y, z)
+                .with_rotation(Quat::from_rotation_y(yaw))
+                .with_scale(Vec3::new(FINGER_X_SCALE, FINGER_SCALE, FINGER_SCALE) * scale),
             DespawnOnExit(Sections::Stairs),
         ));
     }
@@ -86,7 +117,7 @@ fn setup_stairs(
     if let Ok((mut transform, mut look)) = player.single_mut() {
         look.yaw = 0.0;
         look.pitch = 0.0;
-        transform.translation = Vec3::new(0.0, EYE_HEIGHT, STEP_DEPTH);
+        transform.translation = Vec3::new(0.0, player_config.eye_height, STEP_DEPTH);
         transform.rotation = Quat::IDENTITY;
         initial_yaw = look.yaw;
     } else {
@@ -96,13 +127,15 @@ fn setup_stairs(
     // Light at the top of the staircase.
     let top_y = (NUM_STEPS - 1) as f32 * STEP_HEIGHT;
     let top_z = -((NUM_STEPS - 1) as f32 * STEP_DEPTH);
+    let light = PointLight {
+        color: Color::srgb(0.8, 0.7, 1.0),
+        intensity: 200_000.0,
+        range: 150.0,
+        ..default()
+    };
     commands.spawn((
-        PointLight {
-            color: Color::srgb(0.8, 0.7, 1.0),
-            intensity: 200_000.0,
-            range: 150.0,
-            ..default()
-        },
+        TorchFlicker::new(&light, TOP_LIGHT_FLICKER, 3.7),
+        light,
         Transform::from_xyz(0.0, top_y + 5.0, top_z),
         DespawnOnExit(Sections::Stairs),
     ));
@@ -110,7 +143,7 @@ fn setup_stairs(
     commands.insert_resource(StairsState { initial_yaw });
 }
 
-fn stairs_movement(mut player: Query<&mut Transform, With<Player>>) {
+fn stairs_movement(mut player: Query<&mut Transform, With<Player>>, eye: EyeHeight) {
     let Ok(mut transform) = player.single_mut() else {
         return;
     };
@@ -128,7 +161,7 @@ fn stairs_movement(mut player: Query<&mut Transform, With<Player>>) {
     // Snap Y to the current step height based on Z position.
     let progress = (-transform.translation.z / STEP_DEPTH).max(0.0);
     let step_y = progress.floor() * STEP_HEIGHT;
-    transform.translation.y = step_y + EYE_HEIGHT;
+    transform.translation.y = step_y + eye.get();
 }
 
 /// Show the red chevron pointing toward "behind" (the start of the stairs).
@@ -150,48 +183,34 @@ fn stairs_chevron(
 
     // "Behind" is back toward the start of the stairs (+Z from the player).
     let behind_point = camera_global.translation() + Vec3::Z * 20.0;
-
-    let Some(viewport_size) = camera.logical_viewport_size() else {
+    let Some(placement) = point_chevron_at(behind_point, camera, camera_global) else {
         return;
     };
-    let center = viewport_size / 2.0;
-
-    let view_matrix = camera_global.affine().inverse();
-    let behind_view = view_matrix.transform_point3(behind_point);
-
-    let screen_pos = if behind_view.z < 0.0 {
-        // "Behind" is in front of the camera (player turned around).
-        camera
-            .world_to_viewport(camera_global, behind_point)
-            .unwrap_or(center)
-    } else {
-        // "Behind" is behind the camera (normal forward walking).
-        let dir = Vec2::new(behind_view.x, behind_view.y).normalize_or_zero();
-        dir * center.x.min(center.y) * 0.8 + center
-    };
-
-    let clamped_x = screen_pos
-        .x
-        .clamp(CHEVRON_MARGIN, viewport_size.x - CHEVRON_MARGIN);
-    let clamped_y = screen_pos
-        .y
-        .clamp(CHEVRON_MARGIN, viewport_size.y - CHEVRON_MARGIN);
-    node.left = Val::Px(clamped_x - 16.0);
-    node.top = Val::Px(clamped_y - 16.0);
-
-    // Rotate the chevron to point toward the behind-direction on screen.
-    let dir = Vec2::new(screen_pos.x - center.x, screen_pos.y - center.y).normalize_or_zero();
-    let angle = dir.y.atan2(dir.x);
-    ui_transform.rotation = Rot2::radians(angle - std::f32::consts::FRAC_PI_2);
+    placement.apply(&mut node, &mut ui_transform);
 
     *visibility = Visibility::Inherited;
 }
 
+fn climb_rumble(stationary: Res<Stationary>, mut haptics: ResMut<Haptics>) {
+    if stationary.0 == 0.0 {
+        haptics.hold(CLIMB_RUMBLE);
+    }
+}
+
 fn stairs_look_check(
     player: Query<&PlayerLook, With<Player>>,
     state: Res<StairsState>,
+    stationary: Res<Stationary>,
     mut flags: ResMut<PlotFlags>,
+    mut prompt: ResMut<Prompt>,
 ) {
+    // Never a hint to look back; that choice is the player's alone.
+    if stationary.0 > CLIMB_PROMPT_DELAY {
+        prompt.show("Keep climbing");
+    } else {
+        prompt.clear();
+    }
+
     if flags.player_looked_behind {
         return;
     }
@@ -219,15 +238,53 @@ fn stairs_exit(
     let Ok(transform) = player.single() else {
         return;
     };
-    let top_z = -((NUM_STEPS - 2) as f32 * STEP_DEPTH);
-    if transform.translation.z <= top_z {
+    if transform.translation.z <= EXIT_Z {
         next_state.set(Sections::Awaken);
     }
 }
 
-fn exit_stairs(mut commands: Commands, mut chevron: Query<&mut Visibility, With<NpcChevron>>) {
-    commands.insert_resource(GlobalAmbientLight::NONE);
+fn exit_stairs(
+    mut ambient: ResMut<AmbientFade>,
+    mut chevron: Query<&mut Visibility, With<NpcChevron>>,
+) {
+    ambient.fade_out();
     if let Ok(mut vis) = chevron.single_mut() {
         *vis = Visibility::Hidden;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_of_the_stairs_reaches_awaken() {
+        let mut app = crate::sections::headless_app(Sections::Stairs);
+        app.add_systems(Update, stairs_exit.run_if(in_state(Sections::Stairs)));
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)))
+            .id();
+
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<Sections>>().get(),
+            Sections::Stairs
+        );
+
+        app.world_mut()
+            .entity_mut(player)
+            .get_mut::<Transform>()
+            .unwrap()
+            .translation
+            .z = EXIT_Z;
+        // One update to queue the transition, one to apply it.
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<Sections>>().get(),
+            Sections::Awaken
+        );
+    }
+}
@@ -2,23 +2,27 @@
 use bevy::asset::RenderAssetUsages;
 use bevy::mesh::{Indices, PrimitiveTopology};
 use bevy::prelude::*;
-use noiz::prelude::*;
 
 use super::{TerrainConfig, TerrainNoise};
 use crate::terrain::generation::{NoiseSampler, StaleRegion, blend_factor};
 
+/// Highest `chunk_resolution` the edge height arrays can hold. Larger
+/// settings are clamped to it.
+pub const MAX_CHUNK_RESOLUTION: usize = 9;
+
 /// Actual vertex heights along each edge of a generated chunk mesh.
 /// Used to enforce exact height matching at boundaries with stale chunks.
+/// Only the first `chunk_resolution` entries of each edge are used.
 #[derive(Component, Clone, Copy, Debug)]
 pub struct ChunkEdgeHeights {
     /// Heights along zi=0 (min z), indexed by xi.
-    pub north: [f32; 5],
+    pub north: [f32; MAX_CHUNK_RESOLUTION],
     /// Heights along zi=res-1 (max z), indexed by xi.
-    pub south: [f32; 5],
+    pub south: [f32; MAX_CHUNK_RESOLUTION],
     /// Heights along xi=0 (min x), indexed by zi.
-    pub west: [f32; 5],
+    pub west: [f32; MAX_CHUNK_RESOLUTION],
     /// Heights along xi=res-1 (max x), indexed by zi.
-    pub east: [f32; 5],
+    pub east: [f32; MAX_CHUNK_RESOLUTION],
 }
 
 impl ChunkEdgeHeights {
@@ -72,13 +76,13 @@ pub fn terrain_height(
     stale: Option<&StaleRegion>,
 ) -> f32 {
     let p = sampler.noise_point(wx, wz, noise_scale);
-    let h = noise.0.sample_for::<f32>(p) * amplitude;
+    let h = noise.sample(p) * amplitude;
 
     if let Some(stale) = stale {
         let t = blend_factor(wx, wz, stale, chunk_size);
         if t < 1.0 {
             let old_p = stale.sampler.noise_point(wx, wz, noise_scale);
-            let old_h = noise.0.sample_for::<f32>(old_p) * amplitude;
+            let old_h = noise.sample(old_p) * amplitude;
             return old_h + t * (h - old_h);
         }
     }
@@ -97,7 +101,7 @@ pub fn generate_chunk_mesh(
     stale: Option<&StaleRegion>,
 ) -> (Mesh, ChunkEdgeHeights) {
     let size = config.chunk_size;
-    let res = config.chunk_resolution;
+    let res = config.chunk_resolution.clamp(2, MAX_CHUNK_RESOLUTION);
     let step = size / (res - 1) as f32;
     let amplitude = config.amplitude;
     let scale = config.noise_scale;
@@ -158,10 +162,10 @@ pub fn generate_chunk_mesh(
     }
 
     let mut edge_heights = ChunkEdgeHeights {
-        north: [0.0; 5],
-        south: [0.0; 5],
-        west: [0.0; 5],
-        east: [0.0; 5],
+        north: [0.0; MAX_CHUNK_RESOLUTION],
+        south: [0.0; MAX_CHUNK_RESOLUTION],
+        west: [0.0; MAX_CHUNK_RESOLUTION],
+        east: [0.0; MAX_CHUNK_RESOLUTION],
     };
     for xi in 0..res {
         edge_heights.north[xi] = positions[xi][1];
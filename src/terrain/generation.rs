@@ -183,6 +183,7 @@ impl From<DebugColour> for Color {
 /// between them so that center_axis is sampled along the shared seam,
 /// giving C0 continuity.
 #[derive(Clone, Copy, PartialEq, Debug, Reflect, Resource)]
+#[reflect(Resource)]
 pub struct NoiseSampler {
     /// World space axis that is currently visible
     pub visible_axis: VisibleAxis,
@@ -196,6 +197,8 @@ pub struct NoiseSampler {
     pub noise_origin: Vec3,
     /// World-space (x, z) origin where the four quadrants meet
     pub quadrant_origin: Vec2,
+    /// Number of rotations applied since the sampler was created
+    pub rotation_index: u32,
 }
 
 impl Default for NoiseSampler {
@@ -207,6 +210,7 @@ impl Default for NoiseSampler {
             right_axis: Dir3::X,
             noise_origin: Vec3::ZERO,
             quadrant_origin: Vec2::ZERO,
+            rotation_index: 0,
         }
     }
 }
@@ -218,9 +222,8 @@ impl NoiseSampler {
     pub fn noise_point(&self, wx: f32, wz: f32, noise_scale: f32) -> Vec3 {
         let d = Vec2::new(wx - self.quadrant_origin.x, wz - self.quadrant_origin.y);
         let visible_2d = self.visible_axis.dir_2d();
-        let left_2d = self.visible_axis.left().dir_2d();
         let along = d.dot(visible_2d);
-        let lateral = d.dot(left_2d);
+        let lateral = self.lateral(wx, wz);
 
         let across_component = if lateral >= 0.0 {
             lateral * noise_scale * *self.left_axis
@@ -231,6 +234,12 @@ impl NoiseSampler {
         self.noise_origin + along * noise_scale * *self.center_axis + across_component
     }
 
+    /// Signed distance of a world point left of the seam (negative to the right).
+    pub fn lateral(&self, wx: f32, wz: f32) -> f32 {
+        let d = Vec2::new(wx - self.quadrant_origin.x, wz - self.quadrant_origin.y);
+        d.dot(self.visible_axis.left().dir_2d())
+    }
+
     /// Which named quadrant a world point falls in.
     pub fn quadrant_at(&self, wx: f32, wz: f32) -> Quadrant {
         let north = wz < self.quadrant_origin.y;
@@ -256,8 +265,14 @@ impl NoiseSampler {
     }
 
     /// Rotate the noise sampler 90 degrees left. The old left quadrant
-    /// survives as the new right; the new left gets fresh noise.
-    pub fn rotate_left(self, player_pos: Vec2, chunk_size: f32, noise_scale: f32) -> NoiseSampler {
+    /// survives as the new right; the new left gets fresh noise drawn from `rng`.
+    pub fn rotate_left(
+        self,
+        player_pos: Vec2,
+        chunk_size: f32,
+        noise_scale: f32,
+        rng: &mut impl Rng,
+    ) -> NoiseSampler {
         let new_visible = self.visible_axis.left();
         let new_visible_2d = new_visible.dir_2d();
         let snapped_along = (player_pos.dot(new_visible_2d) / chunk_size).floor() * chunk_size;
@@ -265,7 +280,7 @@ impl NoiseSampler {
         let new_origin =
             new_visible_2d * snapped_along + cross_2d * self.quadrant_origin.dot(cross_2d);
 
-        let new_left = random_orthogonal_dir3(self.left_axis);
+        let new_left = random_orthogonal_dir3(self.left_axis, rng);
         let new_center = self.left_axis;
         let new_right = self.center_axis;
 
@@ -284,12 +299,19 @@ impl NoiseSampler {
             right_axis: new_right,
             noise_origin: new_noise_origin,
             quadrant_origin: new_origin,
+            rotation_index: self.rotation_index + 1,
         }
     }
 
     /// Rotate the noise sampler 90 degrees right. The old right quadrant
-    /// survives as the new left; the new right gets fresh noise.
-    pub fn rotate_right(self, player_pos: Vec2, chunk_size: f32, noise_scale: f32) -> NoiseSampler {
+    /// survives as the new left; the new right gets fresh noise drawn from `rng`.
+    pub fn rotate_right(
+        self,
+        player_pos: Vec2,
+        chunk_size: f32,
+        noise_scale: f32,
+        rng: &mut impl Rng,
+    ) -> NoiseSampler {
         let new_visible = self.visible_axis.right();
         let new_visible_2d = new_visible.dir_2d();
         let snapped_along = (player_pos.dot(new_visible_2d) / chunk_size).floor() * chunk_size;
@@ -299,7 +321,7 @@ impl NoiseSampler {
 
         let new_left = self.center_axis;
         let new_center = self.right_axis;
-        let new_right = random_orthogonal_dir3(self.right_axis);
+        let new_right = random_orthogonal_dir3(self.right_axis, rng);
 
         // Adjust noise_origin to preserve the surviving quadrant (old right → new left).
         let d = new_origin - self.quadrant_origin;
@@ -317,6 +339,44 @@ impl NoiseSampler {
             right_axis: new_right,
             noise_origin: new_noise_origin,
             quadrant_origin: new_origin,
+            rotation_index: self.rotation_index + 1,
+        }
+    }
+}
+
+/// Check that a rotation kept the surviving quadrant's noise mapping intact,
+/// sampling a grid of points around `center`. Points that lie on the surviving
+/// side of both the old and new seams must map to the same noise coordinate,
+/// otherwise the `noise_origin` adjustment in `rotate_left`/`rotate_right` is wrong.
+#[cfg(debug_assertions)]
+pub fn debug_check_rotation(
+    old: &NoiseSampler,
+    new: &NoiseSampler,
+    rotated_right: bool,
+    center: Vec2,
+    spacing: f32,
+    noise_scale: f32,
+) {
+    for i in -4..=4 {
+        for j in -4..=4 {
+            let p = center + Vec2::new(i as f32, j as f32) * spacing;
+            let old_lateral = old.lateral(p.x, p.y);
+            let new_lateral = new.lateral(p.x, p.y);
+            // Rotating right keeps the old right side as the new left, and vice versa.
+            let surviving = if rotated_right {
+                old_lateral < 0.0 && new_lateral >= 0.0
+            } else {
+                old_lateral >= 0.0 && new_lateral < 0.0
+            };
+            if !surviving {
+                continue;
+            }
+            let before = old.noise_point(p.x, p.y, noise_scale);
+            let after = new.noise_point(p.x, p.y, noise_scale);
+            debug_assert!(
+                before.distance(after) <= 1e-3 * (1.0 + before.length()),
+                "rotation moved surviving noise at {p}: {before} -> {after}"
+            );
         }
     }
 }
@@ -351,9 +411,14 @@ fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
     t * t * (3.0 - 2.0 * t)
 }
 
+/// Seed for the fresh axis revealed by a given rotation, so the same seed and
+/// rotation index always reveal the same side terrain.
+pub fn rotation_seed(seed: u32, rotation_index: u32) -> u64 {
+    (((seed as u64) << 32) | rotation_index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
 /// Select random Vec3 on unit sphere
-fn random_unit_vec3() -> Vec3 {
-    let mut rng = rand::rng();
+fn random_unit_vec3(rng: &mut impl Rng) -> Vec3 {
     loop {
         let v = Vec3::new(
             rng.random_range(-1.0..1.0),
@@ -367,9 +432,9 @@ fn random_unit_vec3() -> Vec3 {
 }
 
 /// Select random Dir3 orthogonal to that passed in
-fn random_orthogonal_dir3(dir: Dir3) -> Dir3 {
+fn random_orthogonal_dir3(dir: Dir3, rng: &mut impl Rng) -> Dir3 {
     loop {
-        let v = random_unit_vec3();
+        let v = random_unit_vec3(rng);
         let projected = v - v.dot(*dir) * *dir;
         if projected.length_squared() > 0.01 {
             return Dir3::new(projected.normalize())
@@ -377,3 +442,134 @@ fn random_orthogonal_dir3(dir: Dir3) -> Dir3 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::terrain::chunk::terrain_height;
+    use crate::terrain::{TerrainConfig, TerrainNoise, TerrainSeed};
+
+    const CHUNK_SIZE: f32 = 8.0;
+    const NOISE_SCALE: f32 = 0.05;
+
+    fn assert_same_point(a: Vec3, b: Vec3, at: Vec2) {
+        assert!(
+            a.distance(b) <= 1e-4 * (1.0 + a.length()),
+            "noise point at {at} moved: {a} -> {b}"
+        );
+    }
+
+    /// A point ahead of the origin, `lateral` to the left of the seam
+    /// (negative for right).
+    fn ahead(sampler: &NoiseSampler, along: f32, lateral: f32) -> Vec2 {
+        sampler.quadrant_origin
+            + sampler.visible_axis.dir_2d() * along
+            + sampler.visible_axis.left().dir_2d() * lateral
+    }
+
+    /// Left, right, left, left, right, right: every visible axis, turned into
+    /// from both sides.
+    fn rotations() -> Vec<(NoiseSampler, NoiseSampler, bool)> {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut sampler = NoiseSampler::default();
+        let mut steps = Vec::new();
+        for rotate_right in [false, true, false, false, true, true] {
+            let side = if rotate_right { -1.0 } else { 1.0 };
+            let player = ahead(&sampler, 30.0, 20.0 * side);
+            let rotated = if rotate_right {
+                sampler.rotate_right(player, CHUNK_SIZE, NOISE_SCALE, &mut rng)
+            } else {
+                sampler.rotate_left(player, CHUNK_SIZE, NOISE_SCALE, &mut rng)
+            };
+            steps.push((sampler, rotated, rotate_right));
+            sampler = rotated;
+        }
+        steps
+    }
+
+    #[test]
+    fn seam_is_continuous_across_rotations() {
+        let samplers = std::iter::once(NoiseSampler::default())
+            .chain(rotations().into_iter().map(|(_, rotated, _)| rotated));
+        for sampler in samplers {
+            for along in [0.5, 7.0, 23.0, 61.0] {
+                let seam = ahead(&sampler, along, 0.0);
+                let on = sampler.noise_point(seam.x, seam.y, NOISE_SCALE);
+                for eps in [1e-3, 1e-2] {
+                    let left = ahead(&sampler, along, eps);
+                    let right = ahead(&sampler, along, -eps);
+                    // Either side is at most `eps` away in noise space per unit scale.
+                    let limit = 2.0 * eps * NOISE_SCALE + 1e-5;
+                    for p in [left, right] {
+                        let near = sampler.noise_point(p.x, p.y, NOISE_SCALE);
+                        assert!(
+                            on.distance(near) <= limit,
+                            "seam at {seam} jumps to {near} from {on} ({:?})",
+                            sampler.visible_axis
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slide_origin_keeps_heights() {
+        let config = TerrainConfig::default();
+        let noise = TerrainNoise::new(TerrainSeed(7), &config);
+        for (_, sampler, _) in rotations() {
+            let mut slid = sampler;
+            let player = ahead(&sampler, 45.3, 3.1);
+            slid.slide_origin(player, CHUNK_SIZE, NOISE_SCALE);
+            assert_ne!(slid.quadrant_origin, sampler.quadrant_origin);
+            for i in -6..=6 {
+                for j in -6..=6 {
+                    let p = player + Vec2::new(i as f32, j as f32) * 3.7;
+                    assert_same_point(
+                        sampler.noise_point(p.x, p.y, NOISE_SCALE),
+                        slid.noise_point(p.x, p.y, NOISE_SCALE),
+                        p,
+                    );
+                    let height = |s: &NoiseSampler| {
+                        terrain_height(p.x, p.y, &noise, s, 1.0, NOISE_SCALE, CHUNK_SIZE, None)
+                    };
+                    assert!((height(&sampler) - height(&slid)).abs() < 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn surviving_quadrant_keeps_its_noise() {
+        for (old, new, rotated_right) in rotations() {
+            let center = new.quadrant_origin;
+            let mut checked = 0;
+            for i in -10..=10 {
+                for j in -10..=10 {
+                    let p = center + Vec2::new(i as f32, j as f32) * 5.3;
+                    let (old_lateral, new_lateral) = (old.lateral(p.x, p.y), new.lateral(p.x, p.y));
+                    // Rotating right keeps the old right side as the new left,
+                    // and rotating left the old left as the new right.
+                    let surviving = if rotated_right {
+                        old_lateral < 0.0 && new_lateral >= 0.0
+                    } else {
+                        old_lateral >= 0.0 && new_lateral < 0.0
+                    };
+                    if !surviving {
+                        continue;
+                    }
+                    assert_same_point(
+                        old.noise_point(p.x, p.y, NOISE_SCALE),
+                        new.noise_point(p.x, p.y, NOISE_SCALE),
+                        p,
+                    );
+                    checked += 1;
+                }
+            }
+            assert!(checked > 0, "no surviving points sampled");
+        }
+    }
+}
@@ -1,31 +1,55 @@
 // Terrain generation and chunk management.
 mod chunk;
-pub(crate) mod generation;
+pub mod generation;
 mod objects;
+mod wind;
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use noiz::prelude::{common_noise::*, *};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use std::collections::HashSet;
 
-use crate::player::Player;
+use crate::photo::photo_mode_inactive;
+use crate::player::{EyeHeight, Player, SKY_BLUE};
 use crate::sections::Sections;
-use chunk::{ChunkEdgeHeights, generate_chunk_mesh};
+use crate::settings::RenderDistance;
+use crate::transition::CardPhase;
+use chunk::ChunkEdgeHeights;
 
-pub use chunk::terrain_height;
-use generation::{DebugColour, NoiseSampler, StaleRegion, VisibleAxis};
-use objects::{BlueNoisePoints, TerrainObjectAssets};
+pub use chunk::{generate_chunk_mesh, terrain_height};
+use generation::{DebugColour, NoiseSampler, Quadrant, StaleRegion, VisibleAxis, rotation_seed};
+use objects::{BlueNoisePoints, ObjectCount, TerrainObjectAssets};
+use wind::{WindMaterial, WindMaterials};
+
+pub use wind::WindSettings;
 
 pub struct TerrainPlugin;
 
 impl Plugin for TerrainPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<TerrainNoise>()
-            .init_resource::<NoiseSampler>()
+        // The noise is built from the config, so the config goes in first.
+        app.insert_resource(TerrainSeed::from_launch())
             .insert_resource(TerrainConfig::default())
+            .init_resource::<TerrainNoise>()
+            .init_resource::<TerrainRng>()
+            .init_resource::<NoiseSampler>()
             .insert_resource(SpawnedChunks::default())
             .init_resource::<ChunkColours>()
             .init_resource::<StaleChunk>()
             .init_resource::<RotationCount>()
+            .init_resource::<ObjectCount>()
+            .init_resource::<WindSettings>()
+            .init_resource::<WindMaterials>()
+            .add_plugins(MaterialPlugin::<WindMaterial>::default())
+            .add_observer(wind::apply_wind_to_scene)
+            // Registered so an inspector can show and tweak them live.
+            .register_type::<NoiseSampler>()
+            .register_type::<TerrainConfig>()
+            .register_type::<NoiseType>()
+            .register_type::<RotationCount>()
+            .register_type::<WindSettings>()
             .add_systems(
                 Startup,
                 (
@@ -40,33 +64,240 @@ impl Plugin for TerrainPlugin {
                     detect_rotation,
                     update_origin,
                     manage_chunks,
+                    objects::cull_objects,
                     follow_terrain_height,
                 )
                     .chain()
+                    .run_if(in_state(Sections::Chase))
+                    .run_if(photo_mode_inactive),
+            )
+            .add_systems(
+                OnEnter(Sections::Chase),
+                (
+                    prewarm_chunks
+                        .after(crate::player::reset_player)
+                        .after(reset_sampler),
+                    apply_chase_fog,
+                    reseed_rng,
+                    reset_sampler,
+                ),
+            )
+            .add_systems(
+                Update,
+                apply_chase_fog
+                    .run_if(resource_changed::<TerrainConfig>)
                     .run_if(in_state(Sections::Chase)),
+            )
+            .add_systems(OnExit(Sections::Chase), (remove_chase_fog, clear_chunks))
+            // Backstop for anything that slips a chunk in around the exit: by
+            // the end of any frame outside the chase there are none left.
+            .add_systems(
+                Last,
+                clear_chunks
+                    .run_if(not(in_state(Sections::Chase)))
+                    .run_if(chunks_outstanding),
+            )
+            .add_systems(
+                Update,
+                (
+                    objects::fade_in_objects.run_if(in_state(Sections::Chase)),
+                    wind::update_wind.run_if(in_state(Sections::Chase)),
+                    objects::resolve_object_parts
+                        .run_if(|config: Res<TerrainConfig>| config.instance_objects),
+                ),
+            )
+            .add_systems(
+                Update,
+                apply_render_distance.run_if(resource_changed::<RenderDistance>),
+            )
+            .add_systems(
+                Update,
+                rebuild_noise.run_if(resource_changed::<TerrainConfig>),
             );
     }
 }
 
+/// Seed for the terrain noise and for deterministic rotations.
+#[derive(Resource, Clone, Copy)]
+pub struct TerrainSeed(pub u32);
+
+impl Default for TerrainSeed {
+    fn default() -> Self {
+        TerrainSeed(42)
+    }
+}
+
+impl TerrainSeed {
+    /// The seed asked for at launch, or the default. Natively that's
+    /// `--seed <n>` or `EURYDICE_SEED`; on the web it's `#seed=<n>` in the page
+    /// URL, which is written back so the address bar is always a shareable link.
+    pub fn from_launch() -> TerrainSeed {
+        let seed = launch_seed().map(TerrainSeed).unwrap_or_default();
+        #[cfg(target_arch = "wasm32")]
+        if let Some(location) = web_sys::window().map(|window| window.location()) {
+            let _ = location.set_hash(&format!("seed={}", seed.0));
+        }
+        seed
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn launch_seed() -> Option<u32> {
+    let arg = std::env::args().skip_while(|arg| arg != "--seed").nth(1);
+    arg.or_else(|| std::env::var("EURYDICE_SEED").ok())?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn launch_seed() -> Option<u32> {
+    let hash = web_sys::window()?.location().hash().ok()?;
+    hash.trim_start_matches('#')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("seed="))?
+        .parse()
+        .ok()
+}
+
+/// Seeded RNG for rotations when `deterministic_rotation` is off, so a run is
+/// still reproducible from its seed and path.
 #[derive(Resource)]
-pub struct TerrainNoise(pub Noise<Fbm<Perlin>>);
+pub struct TerrainRng(pub StdRng);
 
-impl Default for TerrainNoise {
-    fn default() -> TerrainNoise {
-        let mut noise: Noise<Fbm<Perlin>> = Noise::<Fbm<Perlin>>::default();
-        noise.set_seed(42);
-        noise.set_frequency(2.0);
-        TerrainNoise(noise)
+impl FromWorld for TerrainRng {
+    fn from_world(world: &mut World) -> TerrainRng {
+        let seed = world
+            .get_resource::<TerrainSeed>()
+            .copied()
+            .unwrap_or_default();
+        TerrainRng(StdRng::seed_from_u64(seed.0 as u64))
     }
 }
 
+/// Every run draws the same rotations from the seed, not just the first one
+/// after launch, so a replayed run turns out the same.
+fn reseed_rng(seed: Res<TerrainSeed>, mut rng: ResMut<TerrainRng>) {
+    rng.0 = StdRng::seed_from_u64(seed.0 as u64);
+}
+
+/// Start every run from a fresh sampler, so the seed and rotation index reveal
+/// the same side terrain as they did on the first run after launch.
+fn reset_sampler(mut sampler: ResMut<NoiseSampler>, mut colours: ResMut<ChunkColours>) {
+    *sampler = NoiseSampler::default();
+    *colours = ChunkColours::default();
+}
+
+/// Basis function layered into the terrain's FBM.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+pub enum NoiseType {
+    #[default]
+    Perlin,
+    Simplex,
+    Value,
+}
+
+impl NoiseType {
+    pub fn next(self) -> NoiseType {
+        match self {
+            NoiseType::Perlin => NoiseType::Simplex,
+            NoiseType::Simplex => NoiseType::Value,
+            NoiseType::Value => NoiseType::Perlin,
+        }
+    }
+}
+
+/// The terrain height field. An enum rather than a boxed trait so sampling
+/// stays a plain match in the mesh loops.
 #[derive(Resource)]
+pub enum TerrainNoise {
+    Perlin(Noise<Fbm<Perlin>>),
+    Simplex(Noise<Fbm<Simplex>>),
+    Value(Noise<Fbm<Value>>),
+}
+
+impl TerrainNoise {
+    pub fn new(seed: TerrainSeed, config: &TerrainConfig) -> TerrainNoise {
+        match config.noise_type {
+            NoiseType::Perlin => TerrainNoise::Perlin(fbm(seed, config)),
+            NoiseType::Simplex => TerrainNoise::Simplex(fbm(seed, config)),
+            NoiseType::Value => TerrainNoise::Value(fbm(seed, config)),
+        }
+    }
+
+    pub fn noise_type(&self) -> NoiseType {
+        match self {
+            TerrainNoise::Perlin(_) => NoiseType::Perlin,
+            TerrainNoise::Simplex(_) => NoiseType::Simplex,
+            TerrainNoise::Value(_) => NoiseType::Value,
+        }
+    }
+
+    pub fn sample(&self, p: Vec3) -> f32 {
+        match self {
+            TerrainNoise::Perlin(noise) => noise.sample_for::<f32>(p),
+            TerrainNoise::Simplex(noise) => noise.sample_for::<f32>(p),
+            TerrainNoise::Value(noise) => noise.sample_for::<f32>(p),
+        }
+    }
+}
+
+fn fbm<T: Default>(seed: TerrainSeed, config: &TerrainConfig) -> Noise<Fbm<T>> {
+    let mut noise: Noise<Fbm<T>> = Noise::from(LayeredNoise::new(
+        Normed::default(),
+        Persistence(config.noise_persistence),
+        FractalLayers {
+            layer: Default::default(),
+            lacunarity: config.noise_lacunarity,
+            amount: config.noise_octaves,
+        },
+    ));
+    noise.set_seed(seed.0);
+    noise.set_frequency(config.noise_frequency);
+    noise
+}
+
+impl FromWorld for TerrainNoise {
+    fn from_world(world: &mut World) -> TerrainNoise {
+        let seed = world
+            .get_resource::<TerrainSeed>()
+            .copied()
+            .unwrap_or_default();
+        let config = world
+            .get_resource::<TerrainConfig>()
+            .expect("TerrainConfig is inserted before TerrainNoise");
+        TerrainNoise::new(seed, config)
+    }
+}
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
 pub struct TerrainConfig {
     pub chunk_size: f32,
     pub chunk_resolution: usize,
     pub amplitude: f32,
     pub noise_scale: f32,
+    /// Changing this at runtime rebuilds the noise and respawns the chunks.
+    pub noise_type: NoiseType,
+    /// FBM layers summed for the height field. Fewer is smoother.
+    pub noise_octaves: u32,
+    /// Base frequency of the first layer, before `noise_scale`.
+    pub noise_frequency: f32,
+    /// Frequency multiplier from one layer to the next.
+    pub noise_lacunarity: f32,
+    /// Amplitude multiplier from one layer to the next. Higher is craggier.
+    pub noise_persistence: f32,
     pub render_radius: i32,
+    /// Fraction of blue-noise object placements kept, from 0 (bare) to 1.
+    pub object_density: f32,
+    /// Spawn objects as plain meshes sharing the models' handles, so they
+    /// draw instanced, instead of as one GLTF scene each.
+    pub instance_objects: bool,
+    /// Most terrain objects alive at once across all chunks.
+    pub max_objects: usize,
+    /// Derive each rotation's fresh axis from the seed and rotation index, so
+    /// a given rotation always reveals the same side terrain.
+    pub deterministic_rotation: bool,
 }
 
 impl Default for TerrainConfig {
@@ -76,7 +307,16 @@ impl Default for TerrainConfig {
             chunk_resolution: 5,
             amplitude: 8.0,
             noise_scale: 0.01,
+            noise_type: NoiseType::Perlin,
+            noise_octaves: 8,
+            noise_frequency: 2.0,
+            noise_lacunarity: 2.0,
+            noise_persistence: 0.5,
             render_radius: 16,
+            object_density: 1.0,
+            instance_objects: true,
+            max_objects: 6000,
+            deterministic_rotation: true,
         }
     }
 }
@@ -113,7 +353,8 @@ impl Default for ChunkColours {
 pub struct StaleChunk(pub Option<StaleRegion>);
 
 /// Counts terrain rotations so other systems can react to them.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct RotationCount(pub u32);
 
 #[derive(Component)]
@@ -121,9 +362,14 @@ pub struct TerrainChunk {
     pub grid_pos: (i32, i32),
 }
 
-const EYE_HEIGHT: f32 = 1.5;
+/// Fraction of the render distance that stays clear before fog sets in.
+const FOG_START: f32 = 0.6;
 /// Max chunks to generate per frame to avoid hitches.
 const MAX_SPAWNS_PER_FRAME: usize = 64;
+/// Chunk widths a chunk may fall behind the spawn line before it is despawned.
+/// Chunks spawn only at or ahead of the line, so a player hovering on a row
+/// boundary keeps the row behind them instead of thrashing it.
+const BEHIND_HYSTERESIS: f32 = 1.0;
 
 fn setup_terrain_material(mut commands: Commands, mut materials: ResMut<Assets<StandardMaterial>>) {
     let by_colour = DebugColour::ALL.map(|colour| {
@@ -147,6 +393,8 @@ fn detect_rotation(
     mut stale: ResMut<StaleChunk>,
     mut rotation_count: ResMut<RotationCount>,
     config: Res<TerrainConfig>,
+    seed: Res<TerrainSeed>,
+    mut terrain_rng: ResMut<TerrainRng>,
     player: Query<&Transform, With<Player>>,
     chunks: Query<(Entity, &TerrainChunk, Option<&ChunkEdgeHeights>)>,
 ) {
@@ -212,25 +460,52 @@ fn detect_rotation(
         }
     }
 
-    let (new_sampler, fresh) = if rotating_right {
-        let new = sampler.rotate_right(player_pos, config.chunk_size, config.noise_scale);
-        (new, sector.right_quadrant())
+    let rotate = |rng: &mut StdRng| {
+        if rotating_right {
+            let new = sampler.rotate_right(player_pos, config.chunk_size, config.noise_scale, rng);
+            (new, sector.right_quadrant())
+        } else {
+            let new = sampler.rotate_left(player_pos, config.chunk_size, config.noise_scale, rng);
+            (new, sector.left_quadrant())
+        }
+    };
+    let (new_sampler, fresh) = if config.deterministic_rotation {
+        rotate(&mut StdRng::seed_from_u64(rotation_seed(
+            seed.0,
+            sampler.rotation_index,
+        )))
     } else {
-        let new = sampler.rotate_left(player_pos, config.chunk_size, config.noise_scale);
-        (new, sector.left_quadrant())
+        rotate(&mut terrain_rng.0)
     };
 
-    // Despawn everything behind the new origin along the new visible axis.
+    #[cfg(debug_assertions)]
+    generation::debug_check_rotation(
+        &sampler,
+        &new_sampler,
+        rotating_right,
+        player_pos,
+        config.chunk_size,
+        config.noise_scale,
+    );
+
+    // Despawn everything clearly behind the new origin along the new visible axis.
     let new_visible_2d = sector.dir_2d();
     let origin_along = new_sampler.quadrant_origin.dot(new_visible_2d);
 
     for (entity, chunk, _) in &chunks {
-        if chunk.grid_pos == player_grid {
+        if kept_through_rotation(
+            chunk.grid_pos,
+            player_grid,
+            retiring,
+            &sampler,
+            config.chunk_size,
+        ) {
             continue;
         }
         let center_x = (chunk.grid_pos.0 as f32 + 0.5) * config.chunk_size;
         let center_z = (chunk.grid_pos.1 as f32 + 0.5) * config.chunk_size;
-        if Vec2::new(center_x, center_z).dot(new_visible_2d) < origin_along {
+        let center = Vec2::new(center_x, center_z);
+        if clearly_behind(center, origin_along, new_visible_2d, config.chunk_size) {
             if stale
                 .0
                 .as_ref()
@@ -249,6 +524,123 @@ fn detect_rotation(
     rotation_count.0 += 1;
 }
 
+/// Take the radius from the player's setting. When it shrinks, drop the chunks
+/// now outside it straight away rather than leaving them to the hysteresis
+/// band in `manage_chunks`.
+fn apply_render_distance(
+    mut commands: Commands,
+    distance: Res<RenderDistance>,
+    mut config: ResMut<TerrainConfig>,
+    mut spawned: ResMut<SpawnedChunks>,
+    mut stale: ResMut<StaleChunk>,
+    player: Query<&Transform, With<Player>>,
+    chunks: Query<(Entity, &TerrainChunk)>,
+) {
+    let radius = distance.radius();
+    let shrunk = radius < config.render_radius;
+    config.render_radius = radius;
+    if !shrunk {
+        return;
+    }
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let player_grid = (
+        (transform.translation.x / config.chunk_size).floor() as i32,
+        (transform.translation.z / config.chunk_size).floor() as i32,
+    );
+    for (entity, chunk) in &chunks {
+        let dx = chunk.grid_pos.0 - player_grid.0;
+        let dz = chunk.grid_pos.1 - player_grid.1;
+        if is_player_neighbourhood(chunk.grid_pos, player_grid)
+            || dx * dx + dz * dz <= radius * radius
+        {
+            continue;
+        }
+        if stale
+            .0
+            .as_ref()
+            .is_some_and(|s| s.grid_pos == chunk.grid_pos)
+        {
+            stale.0 = None;
+        }
+        commands.entity(entity).despawn();
+        spawned.0.remove(&chunk.grid_pos);
+    }
+}
+
+/// Drop every chunk and everything tracking them. Queries the live entities
+/// rather than trusting `SpawnedChunks`, so the two can't drift apart.
+fn clear_chunks(
+    mut commands: Commands,
+    mut spawned: ResMut<SpawnedChunks>,
+    mut stale: ResMut<StaleChunk>,
+    mut object_count: ResMut<ObjectCount>,
+    chunks: Query<Entity, With<TerrainChunk>>,
+) {
+    for entity in &chunks {
+        commands.entity(entity).despawn();
+    }
+    spawned.0.clear();
+    stale.0 = None;
+    object_count.0 = 0;
+}
+
+fn chunks_outstanding(spawned: Res<SpawnedChunks>, chunks: Query<(), With<TerrainChunk>>) -> bool {
+    !spawned.0.is_empty() || !chunks.is_empty()
+}
+
+/// Swap in a new basis when the config asks for one. Chunks built from the old
+/// one won't line up with the new, so they all go and stream back in.
+fn rebuild_noise(
+    mut commands: Commands,
+    seed: Res<TerrainSeed>,
+    config: Res<TerrainConfig>,
+    mut noise: ResMut<TerrainNoise>,
+    mut spawned: ResMut<SpawnedChunks>,
+    mut stale: ResMut<StaleChunk>,
+    chunks: Query<Entity, With<TerrainChunk>>,
+) {
+    if noise.noise_type() == config.noise_type {
+        return;
+    }
+    *noise = TerrainNoise::new(*seed, &config);
+    for entity in &chunks {
+        commands.entity(entity).despawn();
+    }
+    spawned.0.clear();
+    stale.0 = None;
+}
+
+/// Fog out to the sky colour by the render radius, so chunks spawning at the
+/// edge fade in from the background instead of popping.
+fn apply_chase_fog(
+    mut commands: Commands,
+    config: Res<TerrainConfig>,
+    player: Query<Entity, With<Player>>,
+) {
+    let Ok(camera) = player.single() else {
+        return;
+    };
+    let end = config.chunk_size * config.render_radius as f32;
+    commands.entity(camera).insert(DistanceFog {
+        // The chase's clear colour, set alongside this on entering.
+        color: SKY_BLUE,
+        falloff: FogFalloff::Linear {
+            start: end * FOG_START,
+            end,
+        },
+        ..default()
+    });
+}
+
+/// The dark sections are lit close in and want no haze.
+fn remove_chase_fog(mut commands: Commands, player: Query<Entity, With<Player>>) {
+    if let Ok(camera) = player.single() {
+        commands.entity(camera).remove::<DistanceFog>();
+    }
+}
+
 /// Keep the quadrant origin one chunk behind the player along the visible axis.
 fn update_origin(
     mut sampler: ResMut<NoiseSampler>,
@@ -262,128 +654,235 @@ fn update_origin(
     sampler.slide_origin(player_pos, config.chunk_size, config.noise_scale);
 }
 
+/// Everything needed to build chunks, shared by the per-frame streaming and
+/// the prewarm pass on entering the chase.
+#[derive(SystemParam)]
+struct ChunkSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: Res<'w, TerrainMaterials>,
+    noise: Res<'w, TerrainNoise>,
+    config: Res<'w, TerrainConfig>,
+    sampler: Res<'w, NoiseSampler>,
+    colours: Res<'w, ChunkColours>,
+    stale: ResMut<'w, StaleChunk>,
+    spawned: ResMut<'w, SpawnedChunks>,
+    blue_noise: Res<'w, BlueNoisePoints>,
+    object_assets: Res<'w, TerrainObjectAssets>,
+    object_count: ResMut<'w, ObjectCount>,
+}
+
+impl ChunkSpawner<'_, '_> {
+    /// Spawn up to `budget` missing chunks within the render radius, forward of
+    /// the player on the visible axis, nearest first.
+    fn spawn_missing(&mut self, player_pos: Vec3, budget: usize) {
+        let config = &*self.config;
+        let player_cx = (player_pos.x / config.chunk_size).floor() as i32;
+        let player_cz = (player_pos.z / config.chunk_size).floor() as i32;
+        let radius = config.render_radius;
+        let radius_sq = radius * radius;
+
+        let visible_2d = self.sampler.visible_axis.dir_2d();
+        let spawn_line = spawn_line(player_cx, player_cz, visible_2d, config.chunk_size);
+
+        let mut candidates = Vec::new();
+        for cz in (player_cz - radius)..(player_cz + radius) {
+            for cx in (player_cx - radius)..(player_cx + radius) {
+                if self.spawned.0.contains(&(cx, cz)) {
+                    continue;
+                }
+
+                let dx = cx - player_cx;
+                let dz = cz - player_cz;
+                let dist_sq = dx * dx + dz * dz;
+                if dist_sq > radius_sq {
+                    continue;
+                }
+
+                let center = Vec2::new(
+                    (cx as f32 + 0.5) * config.chunk_size,
+                    (cz as f32 + 0.5) * config.chunk_size,
+                );
+                if !ahead_of_line(center, spawn_line, visible_2d) {
+                    continue;
+                }
+
+                candidates.push((dist_sq, cx, cz));
+            }
+        }
+
+        // Spawn nearest-first so the world fills in outward from the player.
+        candidates.sort_unstable();
+
+        let stale_ref = self.stale.0.as_ref();
+        for &(_, cx, cz) in candidates.iter().take(budget) {
+            let center = Vec2::new(
+                (cx as f32 + 0.5) * config.chunk_size,
+                (cz as f32 + 0.5) * config.chunk_size,
+            );
+            let quadrant = self.sampler.quadrant_at(center.x, center.y);
+            let colour = self.colours.quadrant_colours[quadrant.index()];
+            let (mesh, edge_heights) =
+                generate_chunk_mesh(cx, cz, config, &self.noise, &self.sampler, stale_ref);
+            let mesh_handle = self.meshes.add(mesh);
+
+            self.commands
+                .spawn((
+                    TerrainChunk { grid_pos: (cx, cz) },
+                    edge_heights,
+                    Mesh3d(mesh_handle),
+                    MeshMaterial3d(self.materials.by_colour[colour as usize].clone()),
+                ))
+                .with_children(|parent| {
+                    objects::spawn_chunk_objects(
+                        parent,
+                        cx,
+                        cz,
+                        config,
+                        &self.noise,
+                        &self.sampler,
+                        stale_ref,
+                        &self.blue_noise,
+                        &self.object_assets,
+                        &mut self.object_count.0,
+                    );
+                });
+
+            self.spawned.0.insert((cx, cz));
+        }
+    }
+}
+
+/// Row boundary just behind the player's chunk; chunks spawn only at or ahead
+/// of it.
+fn spawn_line(player_cx: i32, player_cz: i32, visible_2d: Vec2, chunk_size: f32) -> f32 {
+    let player_center = Vec2::new(
+        (player_cx as f32 + 0.5) * chunk_size,
+        (player_cz as f32 + 0.5) * chunk_size,
+    );
+    player_center.dot(visible_2d) - 0.5 * chunk_size
+}
+
+/// Build the whole starting neighbourhood in one go on entering the chase,
+/// while the title card is still black, so play opens on a finished world.
+fn prewarm_chunks(mut spawner: ChunkSpawner, player: Query<&Transform, With<Player>>) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    spawner.spawn_missing(transform.translation, usize::MAX);
+}
+
 /// Spawn and despawn terrain chunks based on distance and visibility.
 fn manage_chunks(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    materials: Res<TerrainMaterials>,
-    noise: Res<TerrainNoise>,
-    config: Res<TerrainConfig>,
-    sampler: Res<NoiseSampler>,
-    colours: Res<ChunkColours>,
-    mut stale: ResMut<StaleChunk>,
-    mut spawned: ResMut<SpawnedChunks>,
-    blue_noise: Res<BlueNoisePoints>,
-    object_assets: Res<TerrainObjectAssets>,
+    mut spawner: ChunkSpawner,
+    card: Res<CardPhase>,
+    next_section: Res<NextState<Sections>>,
     player: Query<&Transform, With<Player>>,
     chunks: Query<(Entity, &TerrainChunk)>,
 ) {
+    // A transition queued this frame clears the chunks on exit, so anything
+    // built now would only be torn down again, or outlive the clear if the
+    // exit ran first.
+    if !matches!(*next_section, NextState::Unchanged) {
+        return;
+    }
     let Ok(transform) = player.single() else {
         return;
     };
     let player_pos = transform.translation;
+    // While a title card blacks out the screen, build the whole neighbourhood
+    // at once so nothing pops in once it fades.
+    let spawn_budget = if card.is_opaque() {
+        usize::MAX
+    } else {
+        MAX_SPAWNS_PER_FRAME
+    };
 
-    let player_cx = (player_pos.x / config.chunk_size).floor() as i32;
-    let player_cz = (player_pos.z / config.chunk_size).floor() as i32;
-    let radius = config.render_radius;
-    let radius_sq = radius * radius;
+    let chunk_size = spawner.config.chunk_size;
+    let player_cx = (player_pos.x / chunk_size).floor() as i32;
+    let player_cz = (player_pos.z / chunk_size).floor() as i32;
+    let radius = spawner.config.render_radius;
 
-    let visible_2d = sampler.visible_axis.dir_2d();
-    let player_center = Vec2::new(
-        (player_cx as f32 + 0.5) * config.chunk_size,
-        (player_cz as f32 + 0.5) * config.chunk_size,
-    );
-    let player_along = player_center.dot(visible_2d);
+    let visible_2d = spawner.sampler.visible_axis.dir_2d();
+    let spawn_line = spawn_line(player_cx, player_cz, visible_2d, chunk_size);
 
-    // Despawn chunks that are too far or behind the player on the visible axis.
+    // Despawn chunks that are too far or behind the player on the visible axis,
+    // never touching the ground at the player's feet.
     for (entity, chunk) in &chunks {
+        if is_player_neighbourhood(chunk.grid_pos, (player_cx, player_cz)) {
+            continue;
+        }
+
         let dx = chunk.grid_pos.0 - player_cx;
         let dz = chunk.grid_pos.1 - player_cz;
         let dist_sq = dx * dx + dz * dz;
         let too_far = dist_sq > (radius + 2) * (radius + 2);
 
         let center = Vec2::new(
-            (chunk.grid_pos.0 as f32 + 0.5) * config.chunk_size,
-            (chunk.grid_pos.1 as f32 + 0.5) * config.chunk_size,
+            (chunk.grid_pos.0 as f32 + 0.5) * chunk_size,
+            (chunk.grid_pos.1 as f32 + 0.5) * chunk_size,
         );
-        let behind = center.dot(visible_2d) < player_along;
+        let behind = clearly_behind(center, spawn_line, visible_2d, chunk_size);
 
         if too_far || behind {
-            if stale
+            if spawner
+                .stale
                 .0
                 .as_ref()
                 .is_some_and(|s| s.grid_pos == chunk.grid_pos)
             {
-                stale.0 = None;
+                spawner.stale.0 = None;
             }
-            commands.entity(entity).despawn();
-            spawned.0.remove(&chunk.grid_pos);
+            spawner.commands.entity(entity).despawn();
+            spawner.spawned.0.remove(&chunk.grid_pos);
         }
     }
 
-    // Spawn missing chunks forward of the player on the visible axis.
-    let stale_ref = stale.0.as_ref();
-    let mut spawned_this_frame = 0;
-    for cz in (player_cz - radius)..(player_cz + radius) {
-        for cx in (player_cx - radius)..(player_cx + radius) {
-            if spawned_this_frame >= MAX_SPAWNS_PER_FRAME {
-                return;
-            }
-            if spawned.0.contains(&(cx, cz)) {
-                continue;
-            }
-
-            let dx = cx - player_cx;
-            let dz = cz - player_cz;
-            if dx * dx + dz * dz > radius_sq {
-                continue;
-            }
+    spawner.spawn_missing(player_pos, spawn_budget);
+}
 
-            let center = Vec2::new(
-                (cx as f32 + 0.5) * config.chunk_size,
-                (cz as f32 + 0.5) * config.chunk_size,
-            );
-            if center.dot(visible_2d) < player_along {
-                continue;
-            }
+/// The player's chunk and its eight neighbours, which `manage_chunks` never
+/// despawns so the ground under the player stays solid as the origin slides.
+/// Rotations spare them too, except in the retiring quadrant.
+fn is_player_neighbourhood(grid_pos: (i32, i32), player_grid: (i32, i32)) -> bool {
+    (grid_pos.0 - player_grid.0).abs() <= 1 && (grid_pos.1 - player_grid.1).abs() <= 1
+}
 
-            let quadrant = sampler.quadrant_at(center.x, center.y);
-            let colour = colours.quadrant_colours[quadrant.index()];
-            let (mesh, edge_heights) =
-                generate_chunk_mesh(cx, cz, &config, &noise, &sampler, stale_ref);
-            let mesh_handle = meshes.add(mesh);
+/// Whether a rotation spares a chunk wherever it sits: the player's own chunk,
+/// and neighbours outside the retiring quadrant. A retiring neighbour's mesh no
+/// longer matches the sampler, and only the player's chunk is tracked as stale
+/// for new chunks to blend toward, so those get the usual behind test.
+fn kept_through_rotation(
+    grid_pos: (i32, i32),
+    player_grid: (i32, i32),
+    retiring: Quadrant,
+    sampler: &NoiseSampler,
+    chunk_size: f32,
+) -> bool {
+    if grid_pos == player_grid {
+        return true;
+    }
+    let center_x = (grid_pos.0 as f32 + 0.5) * chunk_size;
+    let center_z = (grid_pos.1 as f32 + 0.5) * chunk_size;
+    is_player_neighbourhood(grid_pos, player_grid)
+        && sampler.quadrant_at(center_x, center_z) != retiring
+}
 
-            commands
-                .spawn((
-                    TerrainChunk { grid_pos: (cx, cz) },
-                    edge_heights,
-                    Mesh3d(mesh_handle),
-                    MeshMaterial3d(materials.by_colour[colour as usize].clone()),
-                ))
-                .with_children(|parent| {
-                    objects::spawn_chunk_objects(
-                        parent,
-                        cx,
-                        cz,
-                        &config,
-                        &noise,
-                        &sampler,
-                        stale_ref,
-                        &blue_noise,
-                        &object_assets,
-                    );
-                });
+/// Whether a chunk centre sits at or ahead of `line_along`, where it may spawn.
+fn ahead_of_line(center: Vec2, line_along: f32, visible_2d: Vec2) -> bool {
+    center.dot(visible_2d) >= line_along
+}
 
-            spawned.0.insert((cx, cz));
-            spawned_this_frame += 1;
-        }
-    }
+/// Whether a chunk centre sits more than `BEHIND_HYSTERESIS` chunks behind `line_along`.
+fn clearly_behind(center: Vec2, line_along: f32, visible_2d: Vec2, chunk_size: f32) -> bool {
+    center.dot(visible_2d) < line_along - BEHIND_HYSTERESIS * chunk_size
 }
 
 /// Sample terrain height at the player position so they follow the ground.
 /// Uses blended height when a stale chunk is active to match the actual mesh.
 fn follow_terrain_height(
     mut player: Query<&mut Transform, With<Player>>,
+    eye: EyeHeight,
     noise: Res<TerrainNoise>,
     config: Res<TerrainConfig>,
     sampler: Res<NoiseSampler>,
@@ -402,5 +901,305 @@ fn follow_terrain_height(
         config.chunk_size,
         stale.0.as_ref(),
     );
-    transform.translation.y = height + EYE_HEIGHT;
+    transform.translation.y = height + eye.get();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHUNK_SIZE: f32 = 8.0;
+    const AXES: [VisibleAxis; 4] = [
+        VisibleAxis::North,
+        VisibleAxis::East,
+        VisibleAxis::South,
+        VisibleAxis::West,
+    ];
+
+    fn center(cx: i32, cz: i32) -> Vec2 {
+        Vec2::new(
+            (cx as f32 + 0.5) * CHUNK_SIZE,
+            (cz as f32 + 0.5) * CHUNK_SIZE,
+        )
+    }
+
+    /// A player stepping back and forth across the row boundary between two
+    /// chunks, with chunks spawned and despawned each frame the way
+    /// `spawn_missing` and `manage_chunks` decide. Once the first frame has
+    /// filled in, nothing should come or go.
+    #[test]
+    fn hovering_on_a_row_boundary_keeps_chunks() {
+        for axis in AXES {
+            let visible_2d = axis.dir_2d();
+            let here = (0, 0);
+            let ahead = (visible_2d.x as i32, visible_2d.y as i32);
+            let mut spawned = HashSet::new();
+            let mut churn = 0;
+            for frame in 0..20 {
+                let (player_cx, player_cz) = if frame % 2 == 0 { here } else { ahead };
+                let line = spawn_line(player_cx, player_cz, visible_2d, CHUNK_SIZE);
+
+                let before = spawned.len();
+                spawned.retain(|&(cx, cz)| {
+                    !clearly_behind(center(cx, cz), line, visible_2d, CHUNK_SIZE)
+                });
+                let despawned = before - spawned.len();
+
+                let mut respawned = 0;
+                for cz in -4..=4 {
+                    for cx in -4..=4 {
+                        if ahead_of_line(center(cx, cz), line, visible_2d)
+                            && spawned.insert((cx, cz))
+                        {
+                            respawned += 1;
+                        }
+                    }
+                }
+                if frame > 0 {
+                    churn += despawned + respawned;
+                }
+            }
+            assert_eq!(
+                churn, 0,
+                "chunks thrashed hovering on the {axis:?} boundary"
+            );
+        }
+    }
+
+    /// Without the margin the row just behind the player would be despawned
+    /// the moment they crossed into the next one.
+    #[test]
+    fn row_within_hysteresis_is_kept() {
+        for axis in AXES {
+            let visible_2d = axis.dir_2d();
+            let line = spawn_line(0, 0, visible_2d, CHUNK_SIZE);
+            let step = |rows: i32| center(visible_2d.x as i32 * rows, visible_2d.y as i32 * rows);
+            // The player's row spawns; the one behind it isn't spawned, but
+            // isn't despawned either; two behind is gone.
+            assert!(ahead_of_line(step(0), line, visible_2d));
+            assert!(!ahead_of_line(step(-1), line, visible_2d));
+            assert!(!clearly_behind(step(-1), line, visible_2d, CHUNK_SIZE));
+            assert!(clearly_behind(step(-2), line, visible_2d, CHUNK_SIZE));
+        }
+    }
+
+    #[test]
+    fn leaving_the_chase_clears_every_chunk() {
+        let mut app = crate::sections::headless_app(Sections::Chase);
+        app.init_resource::<SpawnedChunks>()
+            .init_resource::<StaleChunk>()
+            .init_resource::<ObjectCount>()
+            .add_systems(OnExit(Sections::Chase), clear_chunks)
+            .add_systems(
+                Last,
+                clear_chunks
+                    .run_if(not(in_state(Sections::Chase)))
+                    .run_if(chunks_outstanding),
+            )
+            // Queues the exit partway through a frame, then keeps slipping
+            // chunks in on both sides of the transition.
+            .add_systems(
+                Update,
+                |mut next_state: ResMut<NextState<Sections>>,
+                 mut commands: Commands,
+                 mut spawned: ResMut<SpawnedChunks>,
+                 mut frame: Local<i32>| {
+                    if *frame == 0 {
+                        next_state.set(Sections::Menu);
+                    }
+                    commands.spawn(TerrainChunk {
+                        grid_pos: (*frame, 0),
+                    });
+                    spawned.0.insert((*frame, 0));
+                    *frame += 1;
+                },
+            );
+
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(*world.resource::<State<Sections>>().get(), Sections::Menu);
+        assert_eq!(
+            world
+                .query_filtered::<(), With<TerrainChunk>>()
+                .iter(world)
+                .count(),
+            0
+        );
+        assert!(world.resource::<SpawnedChunks>().0.is_empty());
+    }
+
+    #[test]
+    fn every_run_rotates_the_same() {
+        for deterministic_rotation in [true, false] {
+            let mut app = crate::sections::headless_app(Sections::Chase);
+            app.insert_resource(TerrainSeed(7))
+                .insert_resource(TerrainConfig {
+                    deterministic_rotation,
+                    ..default()
+                })
+                .init_resource::<TerrainRng>()
+                .init_resource::<NoiseSampler>()
+                .init_resource::<SpawnedChunks>()
+                .init_resource::<ChunkColours>()
+                .init_resource::<StaleChunk>()
+                .init_resource::<RotationCount>()
+                .add_systems(OnEnter(Sections::Chase), (reseed_rng, reset_sampler))
+                .add_systems(Update, detect_rotation.run_if(in_state(Sections::Chase)));
+            let player = app.world_mut().spawn((Player, Transform::default())).id();
+
+            // Walk a little way along each heading in turn, then look back.
+            let run = |app: &mut App| {
+                let headings = [Vec3::X, Vec3::Z, Vec3::X, Vec3::NEG_Z, Vec3::NEG_X];
+                for (step, heading) in headings.into_iter().enumerate() {
+                    let position = Vec3::new(step as f32 * 11.0, 0.0, step as f32 * -7.0);
+                    *app.world_mut().get_mut::<Transform>(player).unwrap() =
+                        Transform::from_translation(position).looking_to(heading, Vec3::Y);
+                    app.update();
+                }
+                *app.world().resource::<NoiseSampler>()
+            };
+
+            let first = run(&mut app);
+            assert_eq!(first.rotation_index, 5);
+
+            // Back to the start, facing north, and into a new run.
+            *app.world_mut().get_mut::<Transform>(player).unwrap() = Transform::default();
+            for section in [Sections::Menu, Sections::Chase] {
+                app.world_mut()
+                    .resource_mut::<NextState<Sections>>()
+                    .set(section);
+                app.update();
+            }
+            assert_eq!(
+                *app.world().resource::<NoiseSampler>(),
+                NoiseSampler::default()
+            );
+
+            assert_eq!(run(&mut app), first);
+        }
+    }
+
+    /// Height of vertex (xi, zi) if it lies on the chunk's edge.
+    fn edge_height(edges: &ChunkEdgeHeights, xi: usize, zi: usize, res: usize) -> Option<f32> {
+        if zi == 0 {
+            Some(edges.north[xi])
+        } else if zi == res - 1 {
+            Some(edges.south[xi])
+        } else if xi == 0 {
+            Some(edges.west[zi])
+        } else if xi == res - 1 {
+            Some(edges.east[zi])
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn retiring_neighbours_are_not_kept() {
+        let sampler = NoiseSampler::default();
+        let size = TerrainConfig::default().chunk_size;
+        // Just left of the seam, rotating right retires the north-west.
+        let player_grid = (-1, -4);
+        let retiring = sampler.visible_axis.left_quadrant();
+        assert_eq!(retiring, Quadrant::NorthWest);
+        for dz in -1..=1 {
+            for dx in -1..=1 {
+                let grid_pos = (player_grid.0 + dx, player_grid.1 + dz);
+                let kept = kept_through_rotation(grid_pos, player_grid, retiring, &sampler, size);
+                assert_eq!(
+                    kept,
+                    grid_pos == player_grid || grid_pos.0 >= 0,
+                    "{grid_pos:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn kept_neighbours_meet_new_chunks() {
+        let config = TerrainConfig::default();
+        let noise = TerrainNoise::new(TerrainSeed(7), &config);
+        let size = config.chunk_size;
+        let res = config
+            .chunk_resolution
+            .clamp(2, chunk::MAX_CHUNK_RESOLUTION);
+        let sampler = NoiseSampler::default();
+        let player_grid = (-1, -4);
+        let player_pos = Vec2::new(-0.5 * size, -3.5 * size);
+
+        for rotating_right in [true, false] {
+            let mut rng = StdRng::seed_from_u64(7);
+            let (rotated, retiring) = if rotating_right {
+                let rotated = sampler.rotate_right(player_pos, size, config.noise_scale, &mut rng);
+                (rotated, sampler.visible_axis.left_quadrant())
+            } else {
+                let rotated = sampler.rotate_left(player_pos, size, config.noise_scale, &mut rng);
+                (rotated, sampler.visible_axis.right_quadrant())
+            };
+            let old_edges = |grid: (i32, i32)| {
+                generate_chunk_mesh(grid.0, grid.1, &config, &noise, &sampler, None).1
+            };
+            let player_quadrant = sampler.quadrant_at(player_pos.x, player_pos.y);
+            let stale = (player_quadrant == retiring).then(|| StaleRegion {
+                sampler,
+                grid_pos: player_grid,
+                edge_heights: old_edges(player_grid),
+            });
+
+            let mut compared = 0;
+            for kz in -1..=1 {
+                for kx in -1..=1 {
+                    let kept = (player_grid.0 + kx, player_grid.1 + kz);
+                    if !kept_through_rotation(kept, player_grid, retiring, &sampler, size) {
+                        continue;
+                    }
+                    let kept_edges = old_edges(kept);
+                    // Any chunk beside it that isn't kept is built anew.
+                    for nz in -1..=1 {
+                        for nx in -1..=1 {
+                            let built = (kept.0 + nx, kept.1 + nz);
+                            if built == kept
+                                || kept_through_rotation(
+                                    built,
+                                    player_grid,
+                                    retiring,
+                                    &sampler,
+                                    size,
+                                )
+                            {
+                                continue;
+                            }
+                            let (_, built_edges) = generate_chunk_mesh(
+                                built.0,
+                                built.1,
+                                &config,
+                                &noise,
+                                &rotated,
+                                stale.as_ref(),
+                            );
+                            for zi in 0..res {
+                                for xi in 0..res {
+                                    let Some(expected) = kept_edges.shared_height(
+                                        built.0, built.1, xi, zi, kept.0, kept.1, res,
+                                    ) else {
+                                        continue;
+                                    };
+                                    let height = edge_height(&built_edges, xi, zi, res).unwrap();
+                                    assert!(
+                                        (height - expected).abs() < 1e-3,
+                                        "{built:?} against kept {kept:?} at ({xi}, {zi}): \
+                                         {height} != {expected}"
+                                    );
+                                    compared += 1;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            assert!(compared > 0, "no shared edges compared");
+        }
+    }
 }
@@ -1,22 +1,93 @@
 // Terrain object placement using blue noise distribution.
+use bevy::gltf::{Gltf, GltfMesh, GltfNode};
 use bevy::prelude::*;
 use fast_poisson::Poisson2D;
 
+use super::wind::{WindMaterial, WindMaterials};
 use super::{TerrainConfig, TerrainNoise};
+use crate::player::Player;
 use crate::terrain::chunk::terrain_height;
 use crate::terrain::generation::{NoiseSampler, StaleRegion};
 
+/// Max fractional change in object scale either way.
+const OBJECT_SCALE_JITTER: f32 = 0.15;
+
+/// Seconds a newly spawned object takes to grow to full size.
+const SPAWN_FADE_DURATION: f32 = 0.6;
+/// Fraction of full size an object starts at.
+const SPAWN_FADE_START: f32 = 0.05;
+
+/// Share of `max_objects` past which ground cover stops spawning, leaving the
+/// rest of the budget for trees and rocks.
+const GROUND_COVER_HEADROOM: f32 = 0.85;
+
+/// Models matching these stay rigid in the wind; everything else in the
+/// swaying categories bends.
+const RIGID_PREFIXES: [&str; 3] = ["Rock", "Pebble", "Mushroom"];
+
+/// Marks a placed object, so the cap can be enforced across every chunk.
+#[derive(Component)]
+pub struct TerrainObject {
+    ground_cover: bool,
+    /// Whether it takes wind materials once its scene is in.
+    pub sway: bool,
+}
+
+/// Live terrain objects. Counted up as they spawn, and recounted from the
+/// world each frame so despawned chunks come off it.
+#[derive(Resource, Default)]
+pub struct ObjectCount(pub usize);
+
+/// Grows a newly spawned object up to its placed scale, then removes itself.
+/// Scale rather than alpha, since the scene materials are shared.
+#[derive(Component)]
+pub struct SpawnFade {
+    elapsed: f32,
+    target: Vec3,
+}
+
 /// Pre-generated blue noise point set for object placement within a chunk.
 #[derive(Resource)]
 pub struct BlueNoisePoints(Vec<[f32; 2]>);
 
+/// One mesh primitive of an object, in the object's own space.
+struct ObjectPart {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    /// The swaying version of `material`, for models that bend in the wind.
+    wind: Option<Handle<WindMaterial>>,
+    transform: Transform,
+}
+
+/// One object model. Spawned as its GLTF scene until `parts` is resolved, then
+/// as plain meshes on the asset's own handles: a single entity per part with
+/// nothing for the scene spawner to do, and every copy of a part shares its
+/// mesh and material so Bevy draws them instanced.
+struct ObjectKind {
+    scene: Handle<Scene>,
+    gltf: Handle<Gltf>,
+    /// `None` until the GLTF has loaded and been checked, and for good if it
+    /// has a node hierarchy or skin that only the scene reproduces.
+    parts: Option<Vec<ObjectPart>>,
+    checked: bool,
+    sway: bool,
+}
+
+/// Models for one kind of terrain object and how they sit on the ground.
+struct ObjectCategory {
+    kinds: Vec<ObjectKind>,
+    /// How far below the sampled surface the origin is placed, so a base
+    /// spanning a slope doesn't hover over the downhill side.
+    embed_depth: f32,
+}
+
 /// Preloaded scene handles for terrain objects, grouped by category.
 #[derive(Resource)]
 pub struct TerrainObjectAssets {
-    trees: Vec<Handle<Scene>>,
-    dead_trees: Vec<Handle<Scene>>,
-    rocks: Vec<Handle<Scene>>,
-    ground_cover: Vec<Handle<Scene>>,
+    trees: ObjectCategory,
+    dead_trees: ObjectCategory,
+    rocks: ObjectCategory,
+    ground_cover: ObjectCategory,
 }
 
 pub fn setup_blue_noise(mut commands: Commands) {
@@ -28,8 +99,15 @@ pub fn setup_blue_noise(mut commands: Commands) {
 }
 
 pub fn load_terrain_objects(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let load = |name: &str| -> Handle<Scene> {
-        asset_server.load(GltfAssetLabel::Scene(0).from_asset(format!("terrain/{name}.gltf")))
+    let load = |name: &str| -> ObjectKind {
+        let path = format!("terrain/{name}.gltf");
+        ObjectKind {
+            scene: asset_server.load(GltfAssetLabel::Scene(0).from_asset(path.clone())),
+            gltf: asset_server.load(path),
+            parts: None,
+            checked: false,
+            sway: !RIGID_PREFIXES.iter().any(|prefix| name.starts_with(prefix)),
+        }
     };
 
     let trees = vec![
@@ -93,10 +171,22 @@ pub fn load_terrain_objects(mut commands: Commands, asset_server: Res<AssetServe
     ];
 
     commands.insert_resource(TerrainObjectAssets {
-        trees,
-        dead_trees,
-        rocks,
-        ground_cover,
+        trees: ObjectCategory {
+            kinds: trees,
+            embed_depth: 0.3,
+        },
+        dead_trees: ObjectCategory {
+            kinds: dead_trees,
+            embed_depth: 0.3,
+        },
+        rocks: ObjectCategory {
+            kinds: rocks,
+            embed_depth: 0.2,
+        },
+        ground_cover: ObjectCategory {
+            kinds: ground_cover,
+            embed_depth: 0.0,
+        },
     });
 }
 
@@ -111,7 +201,9 @@ pub fn spawn_chunk_objects(
     stale: Option<&StaleRegion>,
     points: &BlueNoisePoints,
     assets: &TerrainObjectAssets,
+    live: &mut usize,
 ) {
+    let cover_cap = (config.max_objects as f32 * GROUND_COVER_HEADROOM) as usize;
     let size = config.chunk_size;
     let origin_x = chunk_x as f32 * size;
     let origin_z = chunk_z as f32 * size;
@@ -125,16 +217,31 @@ pub fn spawn_chunk_objects(
         // rotates, so objects change with the terrain.
         let p = sampler.noise_point(wx, wz, config.noise_scale);
         let t = hash_vec3(p);
+        if hash_vec3(p + Vec3::splat(2.0)) >= config.object_density {
+            continue;
+        }
 
-        let scene = if t > 0.998 && t < 1.0 {
-            pick(&assets.dead_trees, hash_vec3(p + Vec3::X))
+        let (category, frac, ground_cover) = if t > 0.998 && t < 1.0 {
+            (&assets.dead_trees, hash_vec3(p + Vec3::X), false)
         } else if t > 0.995 {
-            pick(&assets.rocks, hash_vec3(p + Vec3::Y))
+            (&assets.rocks, hash_vec3(p + Vec3::Y), false)
         } else if t > 0.985 {
-            pick(&assets.trees, hash_vec3(p + Vec3::X))
+            (&assets.trees, hash_vec3(p + Vec3::X), false)
         } else if t > 0.93 {
-            pick(&assets.ground_cover, hash_vec3(p + Vec3::Z))
+            (&assets.ground_cover, hash_vec3(p + Vec3::Z), true)
+        } else {
+            continue;
+        };
+        let cap = if ground_cover {
+            cover_cap
         } else {
+            config.max_objects
+        };
+        if *live >= cap {
+            continue;
+        }
+        // An empty category places nothing rather than falling through.
+        let Some(kind) = pick(&category.kinds, frac) else {
             continue;
         };
 
@@ -149,17 +256,160 @@ pub fn spawn_chunk_objects(
             stale,
         );
 
-        parent.spawn((
-            SceneRoot(scene.clone()),
-            Transform::from_xyz(wx, height, wz),
+        // Yaw and scale from further offsets of the same hash, so they follow
+        // the seed and the sampler exactly as the object choice does.
+        let yaw = hash_vec3(p + Vec3::new(0.5, 0.0, 0.5)) * std::f32::consts::TAU;
+        let scale = 1.0 + (hash_vec3(p - Vec3::Y) * 2.0 - 1.0) * OBJECT_SCALE_JITTER;
+
+        let target = Vec3::splat(scale);
+        *live += 1;
+        let mut object = parent.spawn((
+            TerrainObject {
+                ground_cover,
+                sway: kind.sway,
+            },
+            Transform::from_xyz(wx, height - category.embed_depth, wz)
+                .with_rotation(Quat::from_rotation_y(yaw))
+                .with_scale(target * SPAWN_FADE_START),
+            SpawnFade {
+                elapsed: 0.0,
+                target,
+            },
         ));
+        match kind.parts.as_ref().filter(|_| config.instance_objects) {
+            Some(parts) => {
+                object
+                    .insert(Visibility::default())
+                    .with_children(|object| {
+                        for part in parts {
+                            let mut part_entity =
+                                object.spawn((Mesh3d(part.mesh.clone()), part.transform));
+                            match &part.wind {
+                                Some(wind) => part_entity.insert(MeshMaterial3d(wind.clone())),
+                                None => part_entity.insert(MeshMaterial3d(part.material.clone())),
+                            };
+                        }
+                    });
+            }
+            None => {
+                object.insert(SceneRoot(kind.scene.clone()));
+            }
+        }
     }
 }
 
-/// Select an item from a list using a fractional index in [0, 1).
-fn pick(items: &[Handle<Scene>], frac: f32) -> &Handle<Scene> {
+/// Pull each object's mesh and material handles out of its GLTF once it has
+/// loaded. Only single-node, unskinned models are taken apart; anything else
+/// keeps spawning as a scene.
+pub fn resolve_object_parts(
+    mut assets: ResMut<TerrainObjectAssets>,
+    gltfs: Res<Assets<Gltf>>,
+    nodes: Res<Assets<GltfNode>>,
+    meshes: Res<Assets<GltfMesh>>,
+    standard: Res<Assets<StandardMaterial>>,
+    mut wind: ResMut<Assets<WindMaterial>>,
+    mut wind_materials: ResMut<WindMaterials>,
+) {
+    let TerrainObjectAssets {
+        trees,
+        dead_trees,
+        rocks,
+        ground_cover,
+    } = &mut *assets;
+    for category in [trees, dead_trees, rocks, ground_cover] {
+        for kind in category.kinds.iter_mut().filter(|kind| !kind.checked) {
+            let Some(gltf) = gltfs.get(&kind.gltf) else {
+                continue;
+            };
+            kind.checked = true;
+            let [node] = gltf.nodes.as_slice() else {
+                continue;
+            };
+            let Some(node) = nodes.get(node).filter(|node| node.skin.is_none()) else {
+                continue;
+            };
+            let Some(mesh) = node.mesh.as_ref().and_then(|mesh| meshes.get(mesh)) else {
+                continue;
+            };
+            kind.parts = Some(
+                mesh.primitives
+                    .iter()
+                    .map(|primitive| {
+                        let material = primitive.material.clone().unwrap_or_default();
+                        ObjectPart {
+                            mesh: primitive.mesh.clone(),
+                            wind: kind
+                                .sway
+                                .then(|| wind_materials.get_or_add(&material, &standard, &mut wind))
+                                .flatten(),
+                            material,
+                            transform: node.transform,
+                        }
+                    })
+                    .collect(),
+            );
+        }
+    }
+}
+
+/// Keep the live count honest and, past the cap (after a preset change, say),
+/// despawn the furthest ground cover first, then the furthest of the rest.
+pub fn cull_objects(
+    mut commands: Commands,
+    config: Res<TerrainConfig>,
+    mut count: ResMut<ObjectCount>,
+    objects: Query<(Entity, &TerrainObject, &GlobalTransform)>,
+    player: Query<&GlobalTransform, With<Player>>,
+) {
+    count.0 = objects.iter().len();
+    let excess = count.0.saturating_sub(config.max_objects);
+    if excess == 0 {
+        return;
+    }
+    let Ok(player) = player.single() else {
+        return;
+    };
+    let player = player.translation();
+    let mut ranked: Vec<(bool, f32, Entity)> = objects
+        .iter()
+        .map(|(entity, object, transform)| {
+            (
+                object.ground_cover,
+                transform.translation().distance_squared(player),
+                entity,
+            )
+        })
+        .collect();
+    // Ground cover before everything else, furthest first within each.
+    ranked.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(b.1.total_cmp(&a.1)));
+    for &(_, _, entity) in ranked.iter().take(excess) {
+        commands.entity(entity).despawn();
+    }
+    count.0 -= excess;
+}
+
+pub fn fade_in_objects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut objects: Query<(Entity, &mut Transform, &mut SpawnFade)>,
+) {
+    for (entity, mut transform, mut fade) in &mut objects {
+        fade.elapsed += time.delta_secs();
+        let t = (fade.elapsed / SPAWN_FADE_DURATION).min(1.0);
+        // Ease out so objects settle into place rather than stopping dead.
+        let eased = 1.0 - (1.0 - t).powi(3);
+        transform.scale = fade.target * (SPAWN_FADE_START + (1.0 - SPAWN_FADE_START) * eased);
+        if t >= 1.0 {
+            commands.entity(entity).remove::<SpawnFade>();
+        }
+    }
+}
+
+/// Select an item from a list using a fractional index in [0, 1), or `None`
+/// if the list is empty.
+fn pick<T>(items: &[T], frac: f32) -> Option<&T> {
     let idx = (frac * items.len() as f32) as usize;
-    &items[idx.min(items.len() - 1)]
+    items.get(idx.min(items.len().saturating_sub(1)))
 }
 
 /// GPU-style hash producing a uniform value in [0, 1) from a 3D point.
@@ -170,3 +420,23 @@ fn hash_vec3(p: Vec3) -> f32 {
         .fract()
         .abs()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_from_empty_category_is_none() {
+        assert_eq!(pick::<u8>(&[], 0.5), None);
+        assert_eq!(pick::<u8>(&[], 0.0), None);
+    }
+
+    #[test]
+    fn pick_clamps_to_the_last_item() {
+        let items = [1u8, 2, 3];
+        assert_eq!(pick(&items, 0.0), Some(&1));
+        assert_eq!(pick(&items, 0.5), Some(&2));
+        assert_eq!(pick(&items, 0.999_999), Some(&3));
+        assert_eq!(pick(&items, 1.0), Some(&3));
+    }
+}
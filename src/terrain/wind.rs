@@ -0,0 +1,184 @@
+// Wind sway for trees and ground cover: a vertex shader extension on the
+// objects' own StandardMaterials that pushes upper vertices sideways.
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::scene::SceneInstanceReady;
+use bevy::shader::ShaderRef;
+
+use super::objects::TerrainObject;
+use crate::dream::DreamSettings;
+use crate::settings::AccessibilitySettings;
+
+/// A vegetation material: the model's StandardMaterial with the sway added.
+pub type WindMaterial = ExtendedMaterial<StandardMaterial, WindExtension>;
+
+/// Extra sway at full dream intensity, as a fraction of `strength`.
+const DREAM_WIND_GAIN: f32 = 0.8;
+/// Share of the sway kept when reduced motion is on.
+const REDUCED_MOTION_WIND: f32 = 0.2;
+/// Steps the dream's share of the wind moves in. The intensity ramps a little
+/// every frame, and each change re-uploads every wind material.
+const DREAM_WIND_STEPS: f32 = 16.0;
+
+/// Global wind for every swaying object.
+#[derive(Resource, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct WindSettings {
+    /// Metres of sideways travel per metre of height above the model's origin,
+    /// at the peak of a gust.
+    pub strength: f32,
+    /// Sway cycles per second, roughly.
+    pub speed: f32,
+    /// Horizontal direction the wind blows toward, on the XZ plane.
+    pub direction: Vec2,
+}
+
+impl Default for WindSettings {
+    fn default() -> Self {
+        WindSettings {
+            strength: 0.05,
+            speed: 1.2,
+            direction: Vec2::new(1.0, 0.3),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, ShaderType)]
+pub struct WindUniform {
+    direction: Vec2,
+    strength: f32,
+    speed: f32,
+}
+
+#[derive(Asset, AsBindGroup, Reflect, Clone, Default)]
+pub struct WindExtension {
+    #[uniform(100)]
+    #[reflect(ignore)]
+    wind: WindUniform,
+}
+
+impl MaterialExtension for WindExtension {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/wind.wgsl".into()
+    }
+}
+
+/// One wind material per source StandardMaterial, so every copy of a model
+/// still shares a material and stays batched, and the wind they were last
+/// given so new ones start in step.
+#[derive(Resource, Default)]
+pub struct WindMaterials {
+    materials: HashMap<AssetId<StandardMaterial>, Handle<WindMaterial>>,
+    wind: WindUniform,
+}
+
+impl WindMaterials {
+    /// The swaying version of `base`, made on first use. `None` until the
+    /// source material has loaded.
+    pub fn get_or_add(
+        &mut self,
+        base: &Handle<StandardMaterial>,
+        standard: &Assets<StandardMaterial>,
+        wind: &mut Assets<WindMaterial>,
+    ) -> Option<Handle<WindMaterial>> {
+        if let Some(handle) = self.materials.get(&base.id()) {
+            return Some(handle.clone());
+        }
+        let handle = wind.add(WindMaterial {
+            base: standard.get(base)?.clone(),
+            extension: WindExtension { wind: self.wind },
+        });
+        self.materials.insert(base.id(), handle.clone());
+        Some(handle)
+    }
+}
+
+/// Swap the StandardMaterials of a swaying object spawned as a scene for
+/// their wind versions once the scene is in.
+pub fn apply_wind_to_scene(
+    trigger: On<SceneInstanceReady>,
+    mut commands: Commands,
+    objects: Query<&TerrainObject>,
+    children: Query<&Children>,
+    meshes: Query<&MeshMaterial3d<StandardMaterial>>,
+    standard: Res<Assets<StandardMaterial>>,
+    mut wind: ResMut<Assets<WindMaterial>>,
+    mut cache: ResMut<WindMaterials>,
+) {
+    if !objects.get(trigger.entity).is_ok_and(|object| object.sway) {
+        return;
+    }
+    for child in children.iter_descendants(trigger.entity) {
+        let Ok(material) = meshes.get(child) else {
+            continue;
+        };
+        let Some(handle) = cache.get_or_add(&material.0, &standard, &mut wind) else {
+            continue;
+        };
+        commands
+            .entity(child)
+            .remove::<MeshMaterial3d<StandardMaterial>>()
+            .insert(MeshMaterial3d(handle));
+    }
+}
+
+/// The wind every material gets, with the dream's gusts moving in
+/// `DREAM_WIND_STEPS` steps.
+fn wind_uniform(settings: &WindSettings, reduced_motion: bool, intensity: f32) -> WindUniform {
+    let motion = if reduced_motion {
+        REDUCED_MOTION_WIND
+    } else {
+        1.0
+    };
+    let intensity = (intensity * DREAM_WIND_STEPS).round() / DREAM_WIND_STEPS;
+    WindUniform {
+        direction: settings.direction.normalize_or(Vec2::X),
+        strength: settings.strength * (1.0 + intensity * DREAM_WIND_GAIN) * motion,
+        speed: settings.speed,
+    }
+}
+
+/// Push the wind, stirred up by the dream, into every wind material. Only
+/// when something has changed, since touching the materials re-uploads them.
+pub fn update_wind(
+    settings: Res<WindSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    dream: Query<&DreamSettings>,
+    mut cache: ResMut<WindMaterials>,
+    mut materials: ResMut<Assets<WindMaterial>>,
+) {
+    let intensity = dream.iter().next().map_or(0.0, |dream| dream.intensity);
+    let uniform = wind_uniform(&settings, accessibility.reduced_motion, intensity);
+    if cache.wind == uniform {
+        return;
+    }
+    cache.wind = uniform;
+    for (_, material) in materials.iter_mut() {
+        material.extension.wind = uniform;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dream_ramp_moves_the_wind_in_steps() {
+        let settings = WindSettings::default();
+        // A frame's worth of ramp within a step leaves the materials alone...
+        assert!(wind_uniform(&settings, false, 0.50) == wind_uniform(&settings, false, 0.51));
+        // ...and the full ramp still changes the wind, a step at a time.
+        let mut changes = 0;
+        let mut last = wind_uniform(&settings, false, 0.0);
+        for frame in 1..=1000 {
+            let uniform = wind_uniform(&settings, false, frame as f32 / 1000.0);
+            if uniform != last {
+                changes += 1;
+                last = uniform;
+            }
+        }
+        assert_eq!(changes, DREAM_WIND_STEPS as usize);
+    }
+}
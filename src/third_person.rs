@@ -0,0 +1,269 @@
+// Optional third-person view. The player entity stays the eye every section
+// reads; the camera is pulled back behind a visible body only for rendering,
+// layered on and off around gameplay like the head-bob.
+use std::time::Duration;
+
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy::scene::SceneInstanceReady;
+use bevy::transform::TransformSystems;
+
+use crate::photo::PhotoMode;
+use crate::player::{
+    EyeHeight, MOVE_SPEED, Player, PlayerLook, PlayerVelocity, apply_dream_shake, apply_head_bob,
+};
+use crate::sections::Sections;
+use crate::settings::ThirdPerson;
+
+pub struct ThirdPersonPlugin;
+
+impl Plugin for ThirdPersonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, (load_body_assets, spawn_body).chain())
+            .add_systems(Update, (toggle_third_person, animate_body))
+            .add_systems(PreUpdate, remove_camera_boom)
+            .add_systems(
+                PostUpdate,
+                (
+                    sync_body.before(apply_head_bob),
+                    apply_camera_boom
+                        .after(apply_dream_shake)
+                        .before(TransformSystems::Propagate),
+                ),
+            );
+    }
+}
+
+pub(crate) const THIRD_PERSON_KEY: KeyCode = KeyCode::KeyB;
+
+const BODY_PATH: &str = "character/base.gltf";
+const ANIM_IDLE: usize = 8; // Idle_Loop
+const ANIM_WALK: usize = 34; // Walk_Loop
+const ANIM_SPRINT: usize = 31; // Sprint_Loop
+const ANIM_BLEND: Duration = Duration::from_millis(250);
+/// Ground speed below which the body stands idle.
+const WALK_MIN_SPEED: f32 = 0.5;
+/// Ground speed, as a share of the chase's, past which the body sprints.
+const SPRINT_SHARE: f32 = 0.75;
+
+/// How far behind the eye the camera sits when nothing is in the way.
+const BOOM_DISTANCE: f32 = 3.5;
+/// How far above the eye the camera sits.
+const BOOM_HEIGHT: f32 = 0.6;
+/// Gap kept between the camera and whatever it backs into.
+const BOOM_CLEARANCE: f32 = 0.3;
+/// Closest the camera is pulled in, so it never ends up inside the head.
+const BOOM_MIN: f32 = 0.5;
+/// Metres per second the boom extends again once clear. It pulls in at once.
+const BOOM_RELAX: f32 = 4.0;
+
+/// The player's visible body in third person. Hidden otherwise.
+#[derive(Component)]
+pub struct PlayerBody;
+
+/// Render-only camera offset for third person, removed before gameplay runs.
+#[derive(Component, Default)]
+pub struct CameraBoom {
+    offset: Vec3,
+    /// Current boom length, eased back out after a collision shortens it.
+    reach: f32,
+}
+
+#[derive(Resource)]
+struct BodyAssets {
+    scene: Handle<Scene>,
+    graph: Handle<AnimationGraph>,
+    idle: AnimationNodeIndex,
+    walk: AnimationNodeIndex,
+    sprint: AnimationNodeIndex,
+}
+
+fn load_body_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    let mut graph = AnimationGraph::new();
+    let mut clip = |index: usize| {
+        graph.add_clip(
+            asset_server.load(GltfAssetLabel::Animation(index).from_asset(BODY_PATH)),
+            1.0,
+            graph.root,
+        )
+    };
+    let idle = clip(ANIM_IDLE);
+    let walk = clip(ANIM_WALK);
+    let sprint = clip(ANIM_SPRINT);
+    commands.insert_resource(BodyAssets {
+        scene: asset_server.load(GltfAssetLabel::Scene(0).from_asset(BODY_PATH)),
+        graph: graphs.add(graph),
+        idle,
+        walk,
+        sprint,
+    });
+}
+
+fn spawn_body(mut commands: Commands, assets: Res<BodyAssets>) {
+    commands
+        .spawn((
+            PlayerBody,
+            SceneRoot(assets.scene.clone()),
+            Transform::default(),
+            Visibility::Hidden,
+        ))
+        .observe(start_body_animation);
+}
+
+fn start_body_animation(
+    trigger: On<SceneInstanceReady>,
+    assets: Res<BodyAssets>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    mut players: Query<(Entity, &mut AnimationPlayer)>,
+) {
+    for child in children.iter_descendants(trigger.entity) {
+        if let Ok((anim_entity, mut player)) = players.get_mut(child) {
+            let mut transitions = AnimationTransitions::new();
+            transitions
+                .play(&mut player, assets.idle, Duration::ZERO)
+                .repeat();
+            commands
+                .entity(anim_entity)
+                .insert(AnimationGraphHandle(assets.graph.clone()))
+                .insert(transitions);
+            break;
+        }
+    }
+}
+
+fn toggle_third_person(keyboard: Res<ButtonInput<KeyCode>>, mut third: ResMut<ThirdPerson>) {
+    if keyboard.just_pressed(THIRD_PERSON_KEY) {
+        third.0 = !third.0;
+    }
+}
+
+/// Third person only applies while walking, and photo mode keeps its own free
+/// camera.
+fn third_person_active(third: &ThirdPerson, section: &Sections, photo: &PhotoMode) -> bool {
+    third.0
+        && !photo.active
+        && matches!(
+            section,
+            Sections::Chase | Sections::Underworld | Sections::Stairs
+        )
+}
+
+/// Idle, walk or sprint to match the player's ground speed.
+fn animate_body(
+    assets: Res<BodyAssets>,
+    player: Query<&PlayerVelocity, With<Player>>,
+    body: Query<Entity, With<PlayerBody>>,
+    children: Query<&Children>,
+    mut players: Query<(&mut AnimationPlayer, &mut AnimationTransitions)>,
+    mut playing: Local<Option<AnimationNodeIndex>>,
+) {
+    let (Ok(velocity), Ok(body)) = (player.single(), body.single()) else {
+        return;
+    };
+    let speed = velocity.0.length();
+    let wanted = if speed < WALK_MIN_SPEED {
+        assets.idle
+    } else if speed < MOVE_SPEED * SPRINT_SHARE {
+        assets.walk
+    } else {
+        assets.sprint
+    };
+    if *playing == Some(wanted) {
+        return;
+    }
+    for child in children.iter_descendants(body) {
+        if let Ok((mut player, mut transitions)) = players.get_mut(child) {
+            transitions.play(&mut player, wanted, ANIM_BLEND).repeat();
+            *playing = Some(wanted);
+            break;
+        }
+    }
+}
+
+/// Stand the body under the eye, facing where the player looks. Runs before
+/// the head-bob goes on, so the body doesn't bob with the view.
+fn sync_body(
+    third: Res<ThirdPerson>,
+    section: Res<State<Sections>>,
+    photo: Res<PhotoMode>,
+    eye: EyeHeight,
+    player: Query<(&Transform, &PlayerLook), With<Player>>,
+    mut body: Query<(&mut Transform, &mut Visibility), (With<PlayerBody>, Without<Player>)>,
+) {
+    let Ok((mut body_transform, mut visibility)) = body.single_mut() else {
+        return;
+    };
+    if !third_person_active(&third, &section, &photo) {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    let Ok((transform, look)) = player.single() else {
+        return;
+    };
+    *visibility = Visibility::Inherited;
+    body_transform.translation = transform.translation - Vec3::Y * eye.get();
+    // The model faces +Z; the camera looks down -Z.
+    body_transform.rotation = Quat::from_rotation_y(look.yaw + std::f32::consts::PI);
+}
+
+fn remove_camera_boom(mut query: Query<(&mut Transform, &mut CameraBoom), With<Player>>) {
+    let Ok((mut transform, mut boom)) = query.single_mut() else {
+        return;
+    };
+    transform.translation -= boom.offset;
+    boom.offset = Vec3::ZERO;
+}
+
+/// Pull the camera back and up from the eye, shortened to stop short of
+/// anything solid behind it so it never clips through terrain or walls.
+fn apply_camera_boom(
+    time: Res<Time>,
+    third: Res<ThirdPerson>,
+    section: Res<State<Sections>>,
+    photo: Res<PhotoMode>,
+    mut ray_cast: MeshRayCast,
+    mut player: Query<(Entity, &mut Transform, &mut CameraBoom), With<Player>>,
+    body: Query<Entity, With<PlayerBody>>,
+    children: Query<&Children>,
+) {
+    let Ok((player_entity, mut transform, mut boom)) = player.single_mut() else {
+        return;
+    };
+    if !third_person_active(&third, &section, &photo) {
+        boom.reach = 0.0;
+        return;
+    }
+
+    let desired = *transform.back() * BOOM_DISTANCE + Vec3::Y * BOOM_HEIGHT;
+    let Ok(direction) = Dir3::new(desired) else {
+        return;
+    };
+    let full = desired.length();
+
+    // Never collide with the player's own meshes: the body, or the arms
+    // parented to the camera.
+    let own: HashSet<Entity> = body
+        .iter()
+        .flat_map(|body| children.iter_descendants(body))
+        .chain(children.iter_descendants(player_entity))
+        .collect();
+    let filter = |entity: Entity| !own.contains(&entity);
+    let settings = MeshRayCastSettings::default()
+        .with_filter(&filter)
+        .with_visibility(RayCastVisibility::Any);
+    let clear = ray_cast
+        .cast_ray(Ray3d::new(transform.translation, direction), &settings)
+        .first()
+        .map_or(full, |(_, hit)| hit.distance - BOOM_CLEARANCE)
+        .clamp(BOOM_MIN, full);
+
+    boom.reach = clear.min(boom.reach + BOOM_RELAX * time.delta_secs());
+    boom.offset = direction * boom.reach;
+    transform.translation += boom.offset;
+}
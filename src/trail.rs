@@ -0,0 +1,124 @@
+// Faint marks on the ground along the NPC's recent path, so a player who falls
+// behind can still see which way it went.
+
+use bevy::prelude::*;
+
+use crate::npc::Npc;
+use crate::photo::photo_mode_inactive;
+use crate::sections::Sections;
+
+pub struct TrailPlugin;
+
+impl Plugin for TrailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_trail_assets)
+            .add_systems(OnEnter(Sections::Chase), reset_trail)
+            .add_systems(
+                Update,
+                (drop_trail_marks, fade_trail_marks)
+                    .run_if(in_state(Sections::Chase))
+                    .run_if(photo_mode_inactive),
+            );
+    }
+}
+
+/// Seconds between samples of the NPC position.
+const TRAIL_INTERVAL: f32 = 0.3;
+/// Skip a sample if the NPC has moved less than this since the last mark.
+const TRAIL_MIN_SPACING: f32 = 1.0;
+/// Seconds a mark takes to fade away completely.
+const TRAIL_LIFETIME: f32 = 6.0;
+const TRAIL_SIZE: f32 = 0.25;
+const TRAIL_ALPHA: f32 = 0.5;
+/// Lift above the ground to avoid z-fighting with the terrain.
+const TRAIL_LIFT: f32 = 0.05;
+const TRAIL_COLOR: Color = Color::srgb(1.0, 0.95, 0.8);
+
+#[derive(Resource)]
+struct TrailAssets {
+    mesh: Handle<Mesh>,
+}
+
+/// When the next sample is due and where the last mark went.
+#[derive(Resource, Default)]
+struct TrailState {
+    timer: f32,
+    last: Option<Vec3>,
+}
+
+#[derive(Component)]
+struct TrailMark {
+    age: f32,
+}
+
+fn setup_trail_assets(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+    let mesh = meshes.add(Circle::new(TRAIL_SIZE));
+    commands.insert_resource(TrailAssets { mesh });
+}
+
+fn reset_trail(mut commands: Commands) {
+    commands.insert_resource(TrailState::default());
+}
+
+fn drop_trail_marks(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<TrailAssets>,
+    mut state: ResMut<TrailState>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    npc: Query<&Transform, With<Npc>>,
+) {
+    state.timer -= time.delta_secs();
+    if state.timer > 0.0 {
+        return;
+    }
+    state.timer = TRAIL_INTERVAL;
+
+    let Ok(npc) = npc.single() else {
+        return;
+    };
+    // The NPC already sits at terrain height, so its feet mark the ground.
+    let position = npc.translation + Vec3::Y * TRAIL_LIFT;
+    if state
+        .last
+        .is_some_and(|last| last.distance(position) < TRAIL_MIN_SPACING)
+    {
+        return;
+    }
+    state.last = Some(position);
+
+    // Each mark owns its material so it can fade on its own.
+    let material = materials.add(StandardMaterial {
+        base_color: TRAIL_COLOR.with_alpha(TRAIL_ALPHA),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    commands.spawn((
+        TrailMark { age: 0.0 },
+        Mesh3d(assets.mesh.clone()),
+        MeshMaterial3d(material),
+        Transform::from_translation(position)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        DespawnOnExit(Sections::Chase),
+    ));
+}
+
+fn fade_trail_marks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut marks: Query<(Entity, &mut TrailMark, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut mark, material) in &mut marks {
+        mark.age += time.delta_secs();
+        if mark.age >= TRAIL_LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(material) = materials.get_mut(&material.0) {
+            let alpha = TRAIL_ALPHA * (1.0 - mark.age / TRAIL_LIFETIME);
+            material.base_color.set_alpha(alpha);
+        }
+    }
+}
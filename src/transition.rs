@@ -1,4 +1,5 @@
-// Full-screen title cards that fade in and out between sections.
+// Full-screen title cards that fade in and out between sections, and the
+// ambient light easing from one section's glow to the next.
 
 use bevy::prelude::*;
 
@@ -20,7 +21,9 @@ impl Plugin for TransitionPlugin {
         .add_systems(OnEnter(Sections::Awaken), |commands: Commands| {
             spawn_card(commands, "IV: Awakening")
         })
-        .add_systems(Update, fade_card);
+        .init_resource::<CardPhase>()
+        .init_resource::<AmbientFade>()
+        .add_systems(Update, (fade_card, fade_ambient));
     }
 }
 
@@ -28,10 +31,68 @@ const FADE_IN: f32 = 0.1;
 const HOLD: f32 = 1.5;
 const FADE_OUT: f32 = 1.0;
 const TOTAL: f32 = FADE_IN + HOLD + FADE_OUT;
+/// Seconds for the ambient light to reach a new section's level. Longer than
+/// the card's hold, so the tail of it plays out as the card lifts.
+const AMBIENT_FADE: f32 = 2.5;
 
 #[derive(Resource)]
 struct CardTimer(f32);
 
+/// Where the current title card is in its fade. Sections can read this to do
+/// heavy or jarring setup while the screen is still fully black.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardPhase {
+    /// No card on screen.
+    #[default]
+    Clear,
+    FadeIn,
+    Hold,
+    FadeOut,
+}
+
+impl CardPhase {
+    /// Whether the card's background is fully opaque.
+    pub fn is_opaque(self) -> bool {
+        matches!(self, CardPhase::FadeIn | CardPhase::Hold)
+    }
+}
+
+/// Ambient light on its way from one level to another. Sections ask for their
+/// level with `fade_to` rather than inserting `GlobalAmbientLight` directly.
+#[derive(Resource)]
+pub struct AmbientFade {
+    from: (LinearRgba, f32),
+    to: (LinearRgba, f32),
+    current: (LinearRgba, f32),
+    elapsed: f32,
+}
+
+impl Default for AmbientFade {
+    fn default() -> Self {
+        let none = (LinearRgba::BLACK, 0.0);
+        Self {
+            from: none,
+            to: none,
+            current: none,
+            elapsed: AMBIENT_FADE,
+        }
+    }
+}
+
+impl AmbientFade {
+    /// Start easing from wherever the light is now. An exit fading to nothing
+    /// followed by an entry in the same transition just retargets.
+    pub fn fade_to(&mut self, color: Color, brightness: f32) {
+        self.from = self.current;
+        self.to = (color.to_linear(), brightness);
+        self.elapsed = 0.0;
+    }
+
+    pub fn fade_out(&mut self) {
+        self.fade_to(Color::BLACK, 0.0);
+    }
+}
+
 #[derive(Component)]
 struct CardRoot;
 
@@ -41,6 +102,7 @@ struct CardText;
 fn spawn_card(mut commands: Commands, title: &str) {
     // Despawn any existing card from a previous section.
     commands.insert_resource(CardTimer(0.0));
+    commands.insert_resource(CardPhase::FadeIn);
 
     commands
         .spawn((
@@ -73,6 +135,7 @@ fn fade_card(
     mut commands: Commands,
     time: Res<Time>,
     mut timer: Option<ResMut<CardTimer>>,
+    mut phase: ResMut<CardPhase>,
     roots: Query<Entity, With<CardRoot>>,
     mut texts: Query<&mut TextColor, With<CardText>>,
     mut backgrounds: Query<&mut BackgroundColor, With<CardRoot>>,
@@ -90,6 +153,7 @@ fn fade_card(
             commands.entity(entity).despawn();
         }
         commands.remove_resource::<CardTimer>();
+        *phase = CardPhase::Clear;
         return;
     }
 
@@ -101,15 +165,18 @@ fn fade_card(
         // Fade text in, background stays opaque.
         text_alpha = t / FADE_IN;
         bg_alpha = 1.0;
+        *phase = CardPhase::FadeIn;
     } else if t < FADE_IN + HOLD {
         // Hold.
         text_alpha = 1.0;
         bg_alpha = 1.0;
+        *phase = CardPhase::Hold;
     } else {
         // Fade everything out.
         let fade_t = (t - FADE_IN - HOLD) / FADE_OUT;
         text_alpha = 1.0 - fade_t;
         bg_alpha = 1.0 - fade_t;
+        *phase = CardPhase::FadeOut;
     }
 
     for mut color in &mut texts {
@@ -119,3 +186,21 @@ fn fade_card(
         bg.0 = Color::srgba(0.0, 0.0, 0.0, bg_alpha);
     }
 }
+
+fn fade_ambient(
+    time: Res<Time>,
+    mut fade: ResMut<AmbientFade>,
+    mut ambient: ResMut<GlobalAmbientLight>,
+) {
+    if fade.elapsed >= AMBIENT_FADE {
+        return;
+    }
+    fade.elapsed += time.delta_secs();
+    let t = (fade.elapsed / AMBIENT_FADE).min(1.0);
+    let t = t * t * (3.0 - 2.0 * t);
+    let color = fade.from.0.mix(&fade.to.0, t);
+    let brightness = fade.from.1.lerp(fade.to.1, t);
+    fade.current = (color, brightness);
+    ambient.color = color.into();
+    ambient.brightness = brightness;
+}
@@ -4,17 +4,24 @@ use bevy::asset::RenderAssetUsages;
 use bevy::mesh::{Indices, PrimitiveTopology};
 use bevy::prelude::*;
 use bevy::scene::SceneInstanceReady;
-use noiz::prelude::*;
 
-use crate::player::{Player, PlayerLook};
+use crate::haptics::{HapticCue, Haptics};
+use crate::photo::photo_mode_inactive;
+use crate::player::{EyeHeight, Player, PlayerConfig, PlayerLook};
+use crate::prompt::Prompt;
 use crate::sections::Sections;
 use crate::terrain::TerrainNoise;
+use crate::transition::AmbientFade;
 
 pub struct UnderworldPlugin;
 
 impl Plugin for UnderworldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(Sections::Underworld), setup_underworld)
+        app.init_resource::<UnderworldConfig>()
+            .add_systems(
+                OnEnter(Sections::Underworld),
+                (setup_underworld, apply_underworld_fog),
+            )
             .add_systems(OnExit(Sections::Underworld), exit_underworld)
             .add_systems(
                 Update,
@@ -24,33 +31,100 @@ impl Plugin for UnderworldPlugin {
                     underworld_npc_rotate,
                 )
                     .chain()
-                    .run_if(in_state(Sections::Underworld)),
+                    .run_if(in_state(Sections::Underworld))
+                    .run_if(photo_mode_inactive),
             );
     }
 }
 
-const EYE_HEIGHT: f32 = 1.5;
-
-// Corridor geometry.
-const CORRIDOR_HALF_WIDTH: f32 = 3.0;
-const CORRIDOR_LENGTH: f32 = 100.0;
-const WALL_HEIGHT: f32 = 20.0;
-const WALL_WIDTH: f32 = 3.0;
-const MESH_HALF_WIDTH: f32 = CORRIDOR_HALF_WIDTH + WALL_WIDTH;
-const FLOOR_AMPLITUDE: f32 = 1.0;
-const NOISE_SCALE: f32 = 0.05;
-const MESH_STEP: f32 = 0.5;
-const CLAMP_MARGIN: f32 = 0.5;
-
 // Pool and NPC.
-const POOL_Z: f32 = -90.0;
-const POOL_SIZE: f32 = 4.0;
-const POOL_TRIGGER_DIST: f32 = 5.0;
 const POOL_TRIGGER_PITCH: f32 = -0.5;
+/// Seconds at the pool edge before the player is told to look down.
+const POOL_PROMPT_DELAY: f32 = 2.5;
 const NPC_ROTATION_DURATION: f32 = 3.0;
+/// Pause once upright before the wait toward the stairs begins.
+const NPC_HOLD_DURATION: f32 = 1.0;
 const NPC_WAIT_DURATION: f32 = 3.0;
-const POOL_DEPTH: f32 = 5.0;
-const POOL_BLEND: f32 = 3.0;
+/// A hard jolt as the figure at the pool begins to turn.
+const NPC_TURN_RUMBLE: HapticCue = HapticCue {
+    strong: 1.0,
+    weak: 0.6,
+    duration: 0.35,
+};
+
+/// Corridor and pool layout. The mesh, the player clamp and the pool placement
+/// all read from here, so the walls you see are the walls you hit.
+#[derive(Resource, Clone, Debug)]
+pub struct UnderworldConfig {
+    pub corridor_half_width: f32,
+    pub corridor_length: f32,
+    pub wall_height: f32,
+    pub wall_width: f32,
+    /// Width of the band at the foot of each wall over which the ramp eases
+    /// in, so the floor rounds into the wall instead of creasing.
+    pub wall_blend: f32,
+    /// How much darker the top of a wall is than the open floor, baked into
+    /// vertex colours. 0 disables the occlusion.
+    pub occlusion_strength: f32,
+    pub floor_amplitude: f32,
+    pub noise_scale: f32,
+    pub mesh_step: f32,
+    pub clamp_margin: f32,
+    /// Radius around the figure at the pool edge that the player can't enter.
+    pub npc_keep_out: f32,
+    pub pool_z: f32,
+    pub pool_size: f32,
+    pub pool_trigger_dist: f32,
+    /// Seconds within trigger distance after which the pool fires without the
+    /// player looking down, so standing at the edge can't stall the section.
+    /// `None` leaves looking down as the only trigger.
+    pub pool_linger_trigger: Option<f32>,
+    pub pool_depth: f32,
+    pub pool_blend: f32,
+    /// Distance at which the corridor starts fading to black.
+    pub fog_start: f32,
+    /// Distance past which nothing shows through the dark. The pool sits
+    /// beyond this from the entrance, so it surfaces as the player nears.
+    pub fog_end: f32,
+}
+
+impl Default for UnderworldConfig {
+    fn default() -> Self {
+        Self {
+            corridor_half_width: 3.0,
+            corridor_length: 100.0,
+            wall_height: 20.0,
+            wall_width: 3.0,
+            wall_blend: 1.0,
+            occlusion_strength: 0.6,
+            floor_amplitude: 1.0,
+            noise_scale: 0.05,
+            mesh_step: 0.5,
+            clamp_margin: 0.5,
+            npc_keep_out: 0.8,
+            pool_z: -90.0,
+            pool_size: 4.0,
+            pool_trigger_dist: 5.0,
+            pool_linger_trigger: Some(8.0),
+            pool_depth: 5.0,
+            pool_blend: 3.0,
+            fog_start: 6.0,
+            fog_end: 40.0,
+        }
+    }
+}
+
+impl UnderworldConfig {
+    /// Half width of the generated mesh, including the side walls.
+    fn mesh_half_width(&self) -> f32 {
+        self.corridor_half_width + self.wall_width
+    }
+
+    /// Z of the pool edge nearest the corridor entrance.
+    fn pool_near_z(&self) -> f32 {
+        self.pool_z + self.pool_size * 0.5
+    }
+}
 
 const NPC_PATH: &str = "character/character.gltf";
 const ANIM_TORCH: usize = 10;
@@ -67,86 +141,117 @@ struct UnderworldNpcAnimation {
 #[derive(Resource)]
 struct UnderworldState {
     phase: UnderworldPhase,
+    /// Seconds into the current phase. While walking, seconds spent within
+    /// trigger distance of the pool.
     timer: f32,
+    /// Yaw that turns the NPC to face the player, captured when the rotation starts.
+    npc_target_yaw: f32,
 }
 
 enum UnderworldPhase {
     Walking,
     Rotating,
+    Holding,
     Waiting,
 }
 
-fn base_floor_height(wx: f32, wz: f32, noise: &TerrainNoise) -> f32 {
-    let p = Vec3::new(wx * NOISE_SCALE, 0.0, wz * NOISE_SCALE);
-    noise.0.sample_for::<f32>(p) * FLOOR_AMPLITUDE
+fn base_floor_height(wx: f32, wz: f32, noise: &TerrainNoise, config: &UnderworldConfig) -> f32 {
+    let p = Vec3::new(wx * config.noise_scale, 0.0, wz * config.noise_scale);
+    noise.sample(p) * config.floor_amplitude
 }
 
-fn corridor_floor_height(wx: f32, wz: f32, noise: &TerrainNoise) -> f32 {
-    let base = base_floor_height(wx, wz, noise);
+fn corridor_floor_height(wx: f32, wz: f32, noise: &TerrainNoise, config: &UnderworldConfig) -> f32 {
+    let base = base_floor_height(wx, wz, noise, config);
     // Depress the floor around the pool so terrain doesn't clip the water.
     let dx = wx;
-    let dz = wz - POOL_Z;
+    let dz = wz - config.pool_z;
     let dist = (dx * dx + dz * dz).sqrt();
-    let pool_radius = POOL_SIZE * 0.5 + POOL_BLEND;
+    let pool_radius = config.pool_size * 0.5 + config.pool_blend;
     if dist < pool_radius {
         let t = (1.0 - dist / pool_radius).max(0.0);
-        base - t * t * POOL_DEPTH
+        base - t * t * config.pool_depth
     } else {
         base
     }
 }
 
-fn wall_curve(abs_x: f32) -> f32 {
-    if abs_x <= CORRIDOR_HALF_WIDTH {
+/// Height of a wall `t` of the way from its foot (0) to its top (1). The
+/// quadratic ramp is faded in by a smoothstep over `wall_blend`, which keeps the
+/// curvature continuous where floor meets wall.
+fn wall_ramp(t: f32, config: &UnderworldConfig) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    let band = (config.wall_blend / config.wall_width).max(f32::EPSILON);
+    let s = (t / band).min(1.0);
+    let ease = s * s * (3.0 - 2.0 * s);
+    t * t * ease * config.wall_height
+}
+
+fn wall_curve(abs_x: f32, config: &UnderworldConfig) -> f32 {
+    if abs_x <= config.corridor_half_width {
         0.0
     } else {
-        let t = (abs_x - CORRIDOR_HALF_WIDTH) / WALL_WIDTH;
-        t * t * WALL_HEIGHT
+        wall_ramp(
+            (abs_x - config.corridor_half_width) / config.wall_width,
+            config,
+        )
     }
 }
 
 /// Wall ramp based on proximity to the nearest z-boundary.
-fn end_wall_curve(wz: f32) -> f32 {
+fn end_wall_curve(wz: f32, config: &UnderworldConfig) -> f32 {
     let dist_front = -wz;
-    let dist_back = wz + CORRIDOR_LENGTH;
+    let dist_back = wz + config.corridor_length;
     let nearest = dist_front.min(dist_back).max(0.0);
-    if nearest >= WALL_WIDTH {
+    if nearest >= config.wall_width {
         0.0
     } else {
-        let t = 1.0 - nearest / WALL_WIDTH;
-        t * t * WALL_HEIGHT
+        wall_ramp(1.0 - nearest / config.wall_width, config)
     }
 }
 
-fn corridor_height(wx: f32, wz: f32, noise: &TerrainNoise) -> f32 {
-    corridor_floor_height(wx, wz, noise) + wall_curve(wx.abs()) + end_wall_curve(wz)
+fn corridor_height(wx: f32, wz: f32, noise: &TerrainNoise, config: &UnderworldConfig) -> f32 {
+    corridor_floor_height(wx, wz, noise, config)
+        + wall_curve(wx.abs(), config)
+        + end_wall_curve(wz, config)
 }
 
-fn generate_corridor_mesh(noise: &TerrainNoise) -> Mesh {
-    let width = MESH_HALF_WIDTH * 2.0;
-    let res_x = (width / MESH_STEP) as usize + 1;
-    let res_z = (CORRIDOR_LENGTH / MESH_STEP) as usize + 1;
+fn generate_corridor_mesh(noise: &TerrainNoise, config: &UnderworldConfig) -> Mesh {
+    let step = config.mesh_step;
+    let half_width = config.mesh_half_width();
+    let width = half_width * 2.0;
+    let res_x = (width / step) as usize + 1;
+    let res_z = (config.corridor_length / step) as usize + 1;
 
     let mut positions = Vec::with_capacity(res_x * res_z);
     let mut normals = Vec::with_capacity(res_x * res_z);
+    let mut colours = Vec::with_capacity(res_x * res_z);
     let mut indices = Vec::new();
 
     for zi in 0..res_z {
         for xi in 0..res_x {
-            let wx = (xi as f32 * MESH_STEP) - MESH_HALF_WIDTH;
-            let wz = -(zi as f32 * MESH_STEP);
-            let height = corridor_height(wx, wz, noise);
+            let wx = (xi as f32 * step) - half_width;
+            let wz = -(zi as f32 * step);
+            let height = corridor_height(wx, wz, noise, config);
             positions.push([wx, height, wz]);
 
             // Central-difference normals.
-            let eps = MESH_STEP * 0.5;
+            let eps = step * 0.5;
             let normal = Vec3::new(
-                corridor_height(wx - eps, wz, noise) - corridor_height(wx + eps, wz, noise),
+                corridor_height(wx - eps, wz, noise, config)
+                    - corridor_height(wx + eps, wz, noise, config),
                 2.0 * eps,
-                corridor_height(wx, wz - eps, noise) - corridor_height(wx, wz + eps, noise),
+                corridor_height(wx, wz - eps, noise, config)
+                    - corridor_height(wx, wz + eps, noise, config),
             )
             .normalize();
             normals.push(normal.to_array());
+
+            // Fake ambient occlusion: the further up a wall (or into a corner
+            // where two walls meet), the darker.
+            let wall =
+                (wall_curve(wx.abs(), config) + end_wall_curve(wz, config)) / config.wall_height;
+            let light = 1.0 - config.occlusion_strength * wall.clamp(0.0, 1.0).sqrt();
+            colours.push([light, light, light, 1.0]);
         }
     }
 
@@ -169,6 +274,8 @@ fn generate_corridor_mesh(noise: &TerrainNoise) -> Mesh {
     );
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    // StandardMaterial multiplies base colour by vertex colour when present.
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colours);
     mesh.insert_indices(Indices::U32(indices));
     mesh
 }
@@ -179,18 +286,18 @@ fn setup_underworld(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
     noise: Res<TerrainNoise>,
+    config: Res<UnderworldConfig>,
     asset_server: Res<AssetServer>,
+    player_config: Res<PlayerConfig>,
+    mut ambient: ResMut<AmbientFade>,
     mut player: Query<(&mut Transform, &mut PlayerLook), With<Player>>,
 ) {
-    commands.insert_resource(GlobalAmbientLight {
-        color: Color::srgb(0.4, 0.35, 0.5),
-        brightness: 5.0,
-        affects_lightmapped_meshes: false,
-    });
+    ambient.fade_to(Color::srgb(0.4, 0.35, 0.5), 5.0);
 
     commands.insert_resource(UnderworldState {
         phase: UnderworldPhase::Walking,
         timer: 0.0,
+        npc_target_yaw: 0.0,
     });
 
     // Load NPC torch animation.
@@ -207,16 +314,16 @@ fn setup_underworld(
 
     // Position player at corridor entrance facing north (-Z), past the front wall.
     if let Ok((mut transform, mut look)) = player.single_mut() {
-        let spawn_z = -(WALL_WIDTH + 2.0);
-        let floor_y = corridor_floor_height(0.0, spawn_z, &noise);
-        transform.translation = Vec3::new(0.0, floor_y + EYE_HEIGHT, spawn_z);
+        let spawn_z = -(config.wall_width + 2.0);
+        let floor_y = corridor_floor_height(0.0, spawn_z, &noise, &config);
+        transform.translation = Vec3::new(0.0, floor_y + player_config.eye_height, spawn_z);
         look.yaw = 0.0;
         look.pitch = 0.0;
         transform.rotation = Quat::IDENTITY;
     }
 
     // Corridor mesh.
-    let corridor_mesh = generate_corridor_mesh(&noise);
+    let corridor_mesh = generate_corridor_mesh(&noise, &config);
     let corridor_material = materials.add(StandardMaterial {
         base_color: Color::srgb(0.35, 0.28, 0.22),
         perceptual_roughness: 0.95,
@@ -229,7 +336,7 @@ fn setup_underworld(
     ));
 
     // Pool surface.
-    let pool_y = base_floor_height(0.0, POOL_Z, &noise) - 1.5;
+    let pool_y = base_floor_height(0.0, config.pool_z, &noise, &config) - 1.5;
     let pool_material = materials.add(StandardMaterial {
         base_color: Color::linear_rgba(0.02, 0.02, 0.08, 0.6),
         alpha_mode: AlphaMode::Blend,
@@ -237,15 +344,15 @@ fn setup_underworld(
         ..default()
     });
     commands.spawn((
-        Mesh3d(meshes.add(Rectangle::new(POOL_SIZE, POOL_SIZE))),
+        Mesh3d(meshes.add(Rectangle::new(config.pool_size, config.pool_size))),
         MeshMaterial3d(pool_material),
-        Transform::from_xyz(0.0, pool_y, POOL_Z)
+        Transform::from_xyz(0.0, pool_y, config.pool_z)
             .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
         DespawnOnExit(Sections::Underworld),
     ));
 
     // NPC at the near pool edge, inverted. Rotates upright to face the player.
-    let pool_near_z = POOL_Z + POOL_SIZE * 0.5;
+    let pool_near_z = config.pool_near_z();
     let npc_scene: Handle<Scene> = asset_server.load(GltfAssetLabel::Scene(0).from_asset(NPC_PATH));
     commands
         .spawn((
@@ -276,34 +383,84 @@ fn start_npc_torch(
     }
 }
 
-fn exit_underworld(mut commands: Commands) {
-    commands.insert_resource(GlobalAmbientLight::NONE);
+/// Fade the corridor into the black background ahead, so the torch light
+/// reads as a small bubble in the dark.
+fn apply_underworld_fog(
+    mut commands: Commands,
+    config: Res<UnderworldConfig>,
+    player: Query<Entity, With<Player>>,
+) {
+    let Ok(camera) = player.single() else {
+        return;
+    };
+    commands.entity(camera).insert(DistanceFog {
+        color: Color::BLACK,
+        falloff: FogFalloff::Linear {
+            start: config.fog_start,
+            end: config.fog_end,
+        },
+        ..default()
+    });
+}
+
+fn exit_underworld(
+    mut commands: Commands,
+    mut ambient: ResMut<AmbientFade>,
+    player: Query<Entity, With<Player>>,
+) {
+    ambient.fade_out();
+    if let Ok(camera) = player.single() {
+        commands.entity(camera).remove::<DistanceFog>();
+    }
 }
 
 fn underworld_terrain_follow(
-    mut player: Query<&mut Transform, With<Player>>,
+    mut player: Query<&mut Transform, (With<Player>, Without<UnderworldNpc>)>,
+    npc: Query<&Transform, (With<UnderworldNpc>, Without<Player>)>,
     noise: Res<TerrainNoise>,
+    config: Res<UnderworldConfig>,
+    eye: EyeHeight,
 ) {
     let Ok(mut transform) = player.single_mut() else {
         return;
     };
 
     // Clamp to corridor bounds.
-    transform.translation.x = transform.translation.x.clamp(
-        -(CORRIDOR_HALF_WIDTH - CLAMP_MARGIN),
-        CORRIDOR_HALF_WIDTH - CLAMP_MARGIN,
-    );
-    let pool_edge = POOL_Z + POOL_SIZE * 0.5 + CLAMP_MARGIN;
-    transform.translation.z = transform.translation.z.clamp(pool_edge, -WALL_WIDTH);
+    let half_width = config.corridor_half_width - config.clamp_margin;
+    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
+    let pool_edge = config.pool_near_z() + config.clamp_margin;
+    transform.translation.z = transform.translation.z.clamp(pool_edge, -config.wall_width);
+
+    // Push out of the circle around the figure, back toward the entrance if
+    // standing dead on it, then keep the result inside the corridor.
+    if let Ok(npc) = npc.single() {
+        let offset = transform.translation.xz() - npc.translation.xz();
+        if offset.length() < config.npc_keep_out {
+            let dir = offset.try_normalize().unwrap_or(Vec2::Y);
+            let pushed = npc.translation.xz() + dir * config.npc_keep_out;
+            transform.translation.x = pushed.x.clamp(-half_width, half_width);
+            transform.translation.z = pushed.y.clamp(pool_edge, -config.wall_width);
+        }
+    }
 
     // Follow floor height.
-    let floor_y = corridor_floor_height(transform.translation.x, transform.translation.z, &noise);
-    transform.translation.y = floor_y + EYE_HEIGHT;
+    let floor_y = corridor_floor_height(
+        transform.translation.x,
+        transform.translation.z,
+        &noise,
+        &config,
+    );
+    transform.translation.y = floor_y + eye.get();
 }
 
 fn underworld_pool_check(
     player: Query<(&Transform, &PlayerLook), With<Player>>,
+    npc: Query<&Transform, With<UnderworldNpc>>,
+    config: Res<UnderworldConfig>,
     mut state: ResMut<UnderworldState>,
+    mut prompt: ResMut<Prompt>,
+    mut haptics: ResMut<Haptics>,
+    time: Res<Time>,
 ) {
     if !matches!(state.phase, UnderworldPhase::Walking) {
         return;
@@ -312,12 +469,34 @@ fn underworld_pool_check(
         return;
     };
 
-    let dist_to_pool =
-        Vec2::new(transform.translation.x, transform.translation.z - POOL_Z).length();
+    let dist_to_pool = Vec2::new(
+        transform.translation.x,
+        transform.translation.z - config.pool_z,
+    )
+    .length();
 
-    if dist_to_pool < POOL_TRIGGER_DIST && look.pitch < POOL_TRIGGER_PITCH {
+    if dist_to_pool >= config.pool_trigger_dist {
+        state.timer = 0.0;
+        prompt.clear();
+        return;
+    }
+    state.timer += time.delta_secs();
+    if state.timer > POOL_PROMPT_DELAY {
+        prompt.show("Look into the water");
+    }
+    let lingered = config
+        .pool_linger_trigger
+        .is_some_and(|linger| state.timer >= linger);
+
+    if look.pitch < POOL_TRIGGER_PITCH || lingered {
+        prompt.clear();
+        haptics.pulse(NPC_TURN_RUMBLE);
         state.phase = UnderworldPhase::Rotating;
         state.timer = 0.0;
+        if let Ok(npc_transform) = npc.single() {
+            let to_player = transform.translation - npc_transform.translation;
+            state.npc_target_yaw = to_player.x.atan2(to_player.z);
+        }
     }
 }
 
@@ -332,12 +511,19 @@ fn underworld_npc_rotate(
             state.timer += time.delta_secs();
             let t = (state.timer / NPC_ROTATION_DURATION).min(1.0);
 
+            // Ease out so the figure settles gently rather than stopping dead.
             if let Ok(mut transform) = npc.single_mut() {
-                let angle = std::f32::consts::PI * (1.0 + t);
-                transform.rotation = Quat::from_rotation_x(angle);
+                transform.rotation = npc_rise_rotation(state.npc_target_yaw, ease_out_cubic(t));
             }
 
             if t >= 1.0 {
+                state.phase = UnderworldPhase::Holding;
+                state.timer = 0.0;
+            }
+        }
+        UnderworldPhase::Holding => {
+            state.timer += time.delta_secs();
+            if state.timer >= NPC_HOLD_DURATION {
                 state.phase = UnderworldPhase::Waiting;
                 state.timer = 0.0;
             }
@@ -351,3 +537,58 @@ fn underworld_npc_rotate(
         UnderworldPhase::Walking => {}
     }
 }
+
+/// Stand the NPC up from inverted and turn it toward `target_yaw`.
+/// At `t = 0` the model is flipped about X (feet up, facing -Z); at `t = 1`
+/// the X flip completes a full turn back to upright and the model's +Z forward
+/// points along `target_yaw`.
+fn npc_rise_rotation(target_yaw: f32, t: f32) -> Quat {
+    Quat::from_rotation_y(target_yaw * t) * Quat::from_rotation_x(std::f32::consts::PI * (1.0 + t))
+}
+
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Yaw toward the player, as `underworld_npc_rotate` captures it.
+    fn yaw_toward(to_player: Vec3) -> f32 {
+        to_player.x.atan2(to_player.z)
+    }
+
+    #[test]
+    fn npc_rise_ends_upright_facing_the_player() {
+        let offsets = [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 4.0),
+            Vec3::new(-2.0, 0.0, -5.0),
+        ];
+        for to_player in offsets {
+            let rotation = npc_rise_rotation(yaw_toward(to_player), 1.0);
+            let toward = to_player.normalize();
+            // The model's forward is +Z, so its back, -Z, points away.
+            let back = rotation * Vec3::NEG_Z;
+            assert!(
+                back.dot(-toward) > 0.999,
+                "back {back} should point away from the player along {toward}"
+            );
+            let up = rotation * Vec3::Y;
+            assert!(up.dot(Vec3::Y) > 0.999, "up {up} should stay up");
+        }
+    }
+
+    #[test]
+    fn npc_rise_starts_inverted() {
+        let up = npc_rise_rotation(1.0, 0.0) * Vec3::Y;
+        assert!(
+            up.dot(Vec3::NEG_Y) > 0.999,
+            "up {up} should start pointing down"
+        );
+    }
+}